@@ -0,0 +1,316 @@
+use oort_api::prelude::*;
+
+use super::{
+    control::{Rotation, Translation},
+    draw, error,
+    math::{
+        kinematics::{Position, Velocity},
+        FiringSolution,
+    },
+    radar::{board::ContactBoard, contacts::Contact, CompositeRadar},
+    ship::{trigger::Trigger, weapon::Outcome},
+    tree::{Blackboard, Entry, Node, Status},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Blackboard key for the contact being engaged.
+pub const TARGET: &str = "target";
+
+/// Blackboard key for the contact being evaded.
+pub const THREAT: &str = "threat";
+
+/// Blackboard key for the direction of an evasion in progress.
+const DODGE: &str = "dodge";
+
+/// Bearing within which missiles are launched.
+const MISSILE_BEARING: f64 = PI / 4.0; // rad
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A ship that can be controlled by the leaf nodes.
+///
+pub trait Combatant: Rotation + Translation {
+    fn radar(&self) -> &CompositeRadar;
+    fn radar_mut(&mut self) -> &mut CompositeRadar;
+
+    fn projectile_speed(&self) -> f64;
+    fn max_acceleration(&self) -> f64;
+
+    fn fire_guns(&mut self);
+
+    /// Description
+    /// -----------
+    /// Launch a missile. Launches can take a tick to get ready, so this should be called again
+    /// on the next tick while it's waiting.
+    ///
+    fn launch_missile(&mut self) -> Outcome;
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Pick the closest contact of a class and start tracking it. Sticks with the contact already
+/// picked for as long as it's on the board.
+///
+/// Returns
+/// -------
+/// Success once a contact has been picked, failure if there isn't one.
+///
+pub struct AcquireTarget {
+    key: &'static str,
+    class: Class,
+    range: f64,
+}
+
+impl AcquireTarget {
+    pub fn new(key: &'static str, class: Class) -> Self {
+        return Self {
+            key,
+            class,
+            range: f64::INFINITY,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Only pick contacts within a range.
+    ///
+    pub fn within(self, range: f64) -> Self {
+        return Self { range, ..self };
+    }
+}
+
+impl<C: Combatant> Node<C> for AcquireTarget {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let current = blackboard.contact(self.key);
+        let in_range = |contact: &Contact<_, _>| contact.distance_to(context) <= self.range;
+
+        if let Some(contact) = current.and_then(|id| context.radar().contacts.get(id)) {
+            if in_range(contact) {
+                return Status::Success;
+            }
+        }
+
+        let closest = context
+            .radar()
+            .contacts
+            .iter()
+            .filter(|(_, contact)| contact.class() == self.class && in_range(contact))
+            .min_by(|(_, a), (_, b)| a.distance_to(context).total_cmp(&b.distance_to(context)))
+            .map(|(id, _)| *id);
+
+        if let Some(id) = current {
+            context.radar_mut().stop_tracking(id);
+            blackboard.remove(self.key);
+        }
+
+        let Some(id) = closest else {
+            return Status::Failure;
+        };
+
+        if let Err(error) = context.radar_mut().start_tracking(id) {
+            error::report(error);
+            return Status::Failure;
+        }
+
+        blackboard.set(self.key, Entry::Contact(id));
+        return Status::Success;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Wait for the tracking radar to pick up a contact.
+///
+/// Returns
+/// -------
+/// Success once the contact's tracked, failure if it's lost.
+///
+pub struct Track {
+    key: &'static str,
+}
+
+impl Track {
+    pub fn new(key: &'static str) -> Self {
+        return Self { key };
+    }
+}
+
+impl<C: Combatant> Node<C> for Track {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let contact = blackboard
+            .contact(self.key)
+            .and_then(|id| context.radar().contacts.get(id));
+
+        return match contact {
+            Some(Contact::Tracked(_)) => Status::Success,
+            Some(_) => Status::Running,
+            None => {
+                blackboard.remove(self.key);
+                Status::Failure
+            }
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Close on a tracked contact and shoot at it whenever the trigger says a shot's likely to hit.
+///
+/// Returns
+/// -------
+/// Running while engaging, failure if the contact's lost or can't be hit.
+///
+pub struct EngageGuns {
+    key: &'static str,
+    trigger: Trigger,
+}
+
+impl EngageGuns {
+    pub fn new(key: &'static str, trigger: Trigger) -> Self {
+        return Self { key, trigger };
+    }
+}
+
+impl<C: Combatant> Node<C> for EngageGuns {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let Some(contact) = blackboard
+            .contact(self.key)
+            .and_then(|id| context.radar().contacts.get(id))
+        else {
+            return Status::Failure;
+        };
+
+        let Contact::Tracked(target) = contact else {
+            return Status::Failure;
+        };
+
+        let Ok(solution) = FiringSolution::new(context, context.projectile_speed(), target) else {
+            return Status::Failure;
+        };
+
+        let fire = self.trigger.pull_on(context, &solution, contact);
+
+        context.turn_to_track(&solution);
+        context.accelerate_towards(&solution);
+
+        if fire {
+            context.fire_guns();
+        }
+
+        draw::aim_reticle(&solution);
+        return Status::Running;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Launch a missile at a tracked contact if it's roughly ahead.
+///
+/// Returns
+/// -------
+/// Success if a missile was launched, running while the launch is getting ready and failure
+/// otherwise.
+///
+pub struct LaunchMissile {
+    key: &'static str,
+}
+
+impl LaunchMissile {
+    pub fn new(key: &'static str) -> Self {
+        return Self { key };
+    }
+}
+
+impl<C: Combatant> Node<C> for LaunchMissile {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let Some(Contact::Tracked(target)) = blackboard
+            .contact(self.key)
+            .and_then(|id| context.radar().contacts.get(id))
+        else {
+            return Status::Failure;
+        };
+
+        if context.relative_bearing_to(target).abs() > MISSILE_BEARING {
+            return Status::Failure;
+        }
+
+        return match context.launch_missile() {
+            Outcome::Fired => Status::Success,
+            Outcome::Waiting => Status::Running,
+            Outcome::Reloading(_) => Status::Failure,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Accelerate across the path of an incoming contact to make it miss. Dodges to whichever side
+/// it's already drifting towards and sticks to it until the contact's gone.
+///
+/// Returns
+/// -------
+/// Running while the contact's on the board, success once it's gone.
+///
+pub struct Evade {
+    key: &'static str,
+}
+
+impl Evade {
+    pub fn new(key: &'static str) -> Self {
+        return Self { key };
+    }
+}
+
+impl<C: Combatant> Node<C> for Evade {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let Some(threat) = blackboard
+            .contact(self.key)
+            .and_then(|id| context.radar().contacts.get(id))
+        else {
+            blackboard.remove(self.key);
+            blackboard.remove(DODGE);
+            return Status::Success;
+        };
+
+        let line_of_sight = context.position() - threat.position();
+        let across = vec2(-line_of_sight.y, line_of_sight.x);
+        let across = across / across.length().max(1.0);
+
+        let side = blackboard.vector(DODGE).unwrap_or_else(|| {
+            let drift = context.velocity() - threat.velocity();
+            return if across.dot(drift) < 0.0 {
+                -across
+            } else {
+                across
+            };
+        });
+        blackboard.set(DODGE, Entry::Vector(side));
+
+        // Keep dodging to the same side as the line of sight swings round.
+        let direction = if across.dot(side) < 0.0 {
+            -across
+        } else {
+            across
+        };
+        let acceleration = direction * context.max_acceleration();
+
+        context.turn_to_heading(acceleration.angle());
+        context.set_acceleration(acceleration);
+        return Status::Running;
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,23 @@
+//! Description
+//! -----------
+//! Behaviour trees and state machines for composing roles.
+//!
+
+mod leaves;
+mod state;
+mod task;
+mod tree;
+
+// Imports
+use super::{control, draw, error, math, radar, radio, ship};
+
+// Exports
+pub use self::{
+    leaves::{AcquireTarget, Combatant, EngageGuns, Evade, LaunchMissile, Track, TARGET, THREAT},
+    state::{Event, Hierarchy, Machine, RadarEvents, StateMachine},
+    task::{Executor, Signals},
+    tree::{
+        Action, BehaviourTree, Blackboard, Condition, Decoration, Decorator, Parallel, Selector,
+        Sequence, Status,
+    },
+};
@@ -0,0 +1,441 @@
+use std::{collections::BTreeMap, fmt::Debug};
+
+use oort_api::prelude::*;
+
+use super::{
+    draw::Colour,
+    math::kinematics::Position,
+    radar::{board::ContactBoard, contacts::Contact, CompositeRadar},
+    radio::RadioMessage,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A state that may be nested inside another.
+///
+pub trait Hierarchy: Copy + Eq + Debug {
+    /// Description
+    /// -----------
+    /// Return the state this one is nested in, if any.
+    ///
+    fn parent(self) -> Option<Self>;
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Something that happened which may cause a transition.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub enum Event<'a> {
+    /// Sent once per tick after every other event.
+    Tick,
+
+    /// A contact has appeared on the board.
+    ContactFound(usize),
+
+    /// A contact has been picked up by the tracking radar.
+    ContactTracked(usize),
+
+    /// A contact has gone from the board.
+    ContactLost(usize),
+
+    Message(&'a RadioMessage),
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A ship controlled by a state machine.
+///
+pub trait Machine {
+    type State: Hierarchy;
+
+    /// Description
+    /// -----------
+    /// Entry action. Called for each state entered, outermost first.
+    ///
+    fn enter(&mut self, _state: Self::State) {}
+
+    /// Description
+    /// -----------
+    /// Exit action. Called for each state left, innermost first.
+    ///
+    fn exit(&mut self, _state: Self::State) {}
+
+    /// Description
+    /// -----------
+    /// Return the state to move to from `state` on an event, if any. Guards go here.
+    ///
+    /// Events are offered to the current state first, then to each state it's nested in until one
+    /// of them takes it. This lets an outer state handle events for all of it's inner states.
+    ///
+    fn transition(
+        &self,
+        state: Self::State,
+        event: &Event,
+        machine: &StateMachine<Self::State>,
+    ) -> Option<Self::State>;
+
+    /// Description
+    /// -----------
+    /// Action for the innermost state, run once per tick after any transitions.
+    ///
+    fn run(&mut self, state: Self::State, machine: &StateMachine<Self::State>);
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Keeps track of which state a machine is in and how long it's been there.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct StateMachine<S: Hierarchy> {
+    initial: S,
+
+    /// Current state and those it's nested in, outermost first, with the tick each was entered.
+    path: Vec<(S, u32)>,
+    tick: u32,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl<S: Hierarchy> StateMachine<S> {
+    pub fn new(initial: S) -> Self {
+        return Self {
+            initial,
+            path: Vec::new(),
+            tick: 0,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl<S: Hierarchy> StateMachine<S> {
+    /// Description
+    /// -----------
+    /// Return the innermost state.
+    ///
+    pub fn state(&self) -> S {
+        return self.path.last().map_or(self.initial, |(state, _)| *state);
+    }
+
+    /// Description
+    /// -----------
+    /// Return the number of ticks since a state was entered, if we're in it.
+    ///
+    pub fn ticks_in(&self, state: S) -> Option<u32> {
+        return self
+            .path
+            .iter()
+            .find(|(s, _)| *s == state)
+            .map(|(_, entered)| self.tick - entered);
+    }
+
+    /// Description
+    /// -----------
+    /// Run the machine for one tick.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `machine` - Ship controlled by the state machine.
+    /// * `events` - Events that have happened since the last tick.
+    ///
+    pub fn update<M: Machine<State = S>>(&mut self, machine: &mut M, events: &[Event]) {
+        if self.path.is_empty() {
+            self.enter(machine, self.initial, 0);
+        }
+
+        for event in events.iter().chain([Event::Tick].iter()) {
+            self.dispatch(machine, event);
+        }
+
+        machine.run(self.state(), self);
+        self.tick += 1;
+    }
+
+    /// Description
+    /// -----------
+    /// Offer an event to the current state and each state it's nested in.
+    ///
+    fn dispatch<M: Machine<State = S>>(&mut self, machine: &mut M, event: &Event) {
+        let target = self
+            .path
+            .iter()
+            .rev()
+            .find_map(|(state, _)| machine.transition(*state, event, self));
+
+        if let Some(target) = target {
+            self.transition(machine, target);
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Move to a state, leaving and entering only the states that differ. Moving to the current
+    /// state, or one it's nested in, leaves and re-enters it.
+    ///
+    fn transition<M: Machine<State = S>>(&mut self, machine: &mut M, target: S) {
+        let mut chain = vec![target];
+        while let Some(parent) = chain.last().and_then(|state| state.parent()) {
+            chain.push(parent);
+        }
+        chain.reverse();
+
+        let shared = self
+            .path
+            .iter()
+            .zip(chain.iter())
+            .take_while(|((current, _), target)| current == *target)
+            .count()
+            .min(chain.len() - 1);
+
+        while self.path.len() > shared {
+            if let Some((state, _)) = self.path.pop() {
+                machine.exit(state);
+            }
+        }
+
+        for state in chain.into_iter().skip(shared) {
+            self.enter(machine, state, self.tick);
+        }
+    }
+
+    fn enter<M: Machine<State = S>>(&mut self, machine: &mut M, state: S, tick: u32) {
+        let mut chain = Vec::new();
+        if self.path.is_empty() {
+            let mut outer = state.parent();
+            while let Some(parent) = outer {
+                chain.push(parent);
+                outer = parent.parent();
+            }
+        }
+
+        for state in chain.into_iter().rev().chain([state]) {
+            machine.enter(state);
+            self.path.push((state, tick));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl<S: Hierarchy> StateMachine<S> {
+    /// Description
+    /// -----------
+    /// Show the current state and those it's nested in next to a ship.
+    ///
+    pub fn draw<T: Position>(&self, vessel: &T) {
+        let path: Vec<String> = self
+            .path
+            .iter()
+            .map(|(state, _)| format!("{state:?}"))
+            .collect();
+        let path = path.join(" > ");
+
+        debug!("State: {path}");
+        draw_text!(
+            vessel.position() + vec2(50.0, 50.0),
+            Colour::White as u32,
+            "{path}"
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Turns changes on the contact board into events.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RadarEvents {
+    /// Contacts seen last tick and whether they were tracked.
+    known: BTreeMap<usize, bool>,
+}
+
+impl RadarEvents {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Description
+    /// -----------
+    /// Return the events since the last call. Should be called once per tick after scanning.
+    ///
+    pub fn update(&mut self, radar: &CompositeRadar) -> Vec<Event<'static>> {
+        let current: BTreeMap<usize, bool> = radar
+            .contacts
+            .iter()
+            .map(|(id, contact)| (*id, matches!(contact, Contact::Tracked(_))))
+            .collect();
+
+        let mut events: Vec<Event> = self
+            .known
+            .keys()
+            .filter(|id| !current.contains_key(id))
+            .map(|id| Event::ContactLost(*id))
+            .collect();
+
+        for (id, tracked) in current.iter() {
+            match self.known.get(id) {
+                None => events.push(Event::ContactFound(*id)),
+                Some(false) if *tracked => (),
+                _ => continue,
+            }
+
+            if *tracked {
+                events.push(Event::ContactTracked(*id));
+            }
+        }
+
+        self.known = current;
+        return events;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::radar::{board::UniqueContactBoard, contacts::RemoteContact};
+
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum Test {
+        Idle,
+        Busy,
+        Working,
+        Resting,
+    }
+
+    impl Hierarchy for Test {
+        fn parent(self) -> Option<Self> {
+            return match self {
+                Test::Working | Test::Resting => Some(Test::Busy),
+                _ => None,
+            };
+        }
+    }
+
+    /// Records entry and exit actions and the state run each tick.
+    #[derive(Default)]
+    struct Log {
+        actions: Vec<String>,
+        ran: Option<Test>,
+        tired: bool,
+    }
+
+    impl Machine for Log {
+        type State = Test;
+
+        fn enter(&mut self, state: Test) {
+            self.actions.push(format!("enter {state:?}"));
+        }
+
+        fn exit(&mut self, state: Test) {
+            self.actions.push(format!("exit {state:?}"));
+        }
+
+        fn transition(
+            &self,
+            state: Test,
+            event: &Event,
+            machine: &StateMachine<Test>,
+        ) -> Option<Test> {
+            return match (state, event) {
+                (Test::Idle, Event::ContactFound(_)) => Some(Test::Working),
+                (Test::Working, Event::Tick) if self.tired => Some(Test::Resting),
+                (Test::Resting, Event::Tick) if machine.ticks_in(state) >= Some(2) => {
+                    Some(Test::Working)
+                }
+                (Test::Busy, Event::ContactLost(_)) => Some(Test::Idle),
+                _ => None,
+            };
+        }
+
+        fn run(&mut self, state: Test, _: &StateMachine<Test>) {
+            self.ran = Some(state);
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let mut log = Log::default();
+        let mut machine = StateMachine::new(Test::Idle);
+
+        machine.update(&mut log, &[]);
+        assert_eq!(log.actions, ["enter Idle"]);
+        assert_eq!(log.ran, Some(Test::Idle));
+
+        // Nested states are entered outermost first.
+        log.actions.clear();
+        machine.update(&mut log, &[Event::ContactFound(1)]);
+        assert_eq!(log.actions, ["exit Idle", "enter Busy", "enter Working"]);
+        assert_eq!(log.ran, Some(Test::Working));
+        assert_eq!(machine.ticks_in(Test::Busy), Some(1));
+
+        // Guarded transitions between siblings don't leave the outer state.
+        log.actions.clear();
+        log.tired = true;
+        machine.update(&mut log, &[]);
+        assert_eq!(log.actions, ["exit Working", "enter Resting"]);
+
+        // The outer state handles events it's inner states don't.
+        log.actions.clear();
+        machine.update(&mut log, &[Event::ContactLost(1)]);
+        assert_eq!(log.actions, ["exit Resting", "exit Busy", "enter Idle"]);
+        assert_eq!(machine.state(), Test::Idle);
+    }
+
+    #[test]
+    fn test_timers() {
+        let mut log = Log {
+            tired: true,
+            ..Log::default()
+        };
+        let mut machine = StateMachine::new(Test::Resting);
+
+        machine.update(&mut log, &[]);
+        assert_eq!(log.actions, ["enter Busy", "enter Resting"]);
+        assert_eq!(machine.ticks_in(Test::Resting), Some(1));
+        assert_eq!(machine.ticks_in(Test::Idle), None);
+
+        machine.update(&mut log, &[]);
+        assert_eq!(machine.state(), Test::Resting);
+
+        machine.update(&mut log, &[]);
+        assert_eq!(machine.state(), Test::Working);
+        assert_eq!(machine.ticks_in(Test::Busy), Some(3));
+    }
+
+    #[test]
+    fn test_radar_events() {
+        let mut radar = CompositeRadar::new(UniqueContactBoard::new());
+        let mut events = RadarEvents::new();
+        assert_eq!(events.update(&radar), []);
+
+        let id = radar.fuse(RemoteContact::new(
+            9,
+            0.0,
+            Class::Fighter,
+            vec2(0.0, 0.0),
+            vec2(0.0, 0.0),
+            10.0,
+        ));
+        assert_eq!(events.update(&radar), [Event::ContactFound(id)]);
+        assert_eq!(events.update(&radar), []);
+
+        radar.contacts.remove(id);
+        assert_eq!(events.update(&radar), [Event::ContactLost(id)]);
+    }
+}
+
+////////////////////////////////////////////////////////////////
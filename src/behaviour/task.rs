@@ -0,0 +1,349 @@
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use super::{radio::RadioMessage, state::Event};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// What's happened on the current tick, shared between the executor and it's tasks. Events and
+/// messages are numbered so that each can only be waited on once.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+struct Shared {
+    tick: u32,
+    events: Vec<(u64, Event<'static>)>,
+    messages: Vec<(u64, RadioMessage)>,
+    sequence: u64,
+}
+
+/// Description
+/// -----------
+/// Waker that does nothing. Every task is polled once per tick anyway so there's nothing to wake.
+///
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Runs behaviours that span many ticks as async tasks. Each task is polled once per tick and
+/// picks up where it left off, so a sequence of manoeuvres can be written as straight-line code
+/// that awaits the next tick, a timer, or an event.
+///
+pub struct Executor {
+    tasks: Vec<Task>,
+    shared: Rc<RefCell<Shared>>,
+}
+
+/// Description
+/// -----------
+/// Handle given to tasks for waiting on ticks and events. Once an event or message has been
+/// waited on it won't be seen again through the same handle, so a task can wait on several in a
+/// row within one tick.
+///
+#[derive(Clone)]
+pub struct Signals {
+    shared: Rc<RefCell<Shared>>,
+
+    /// Numbers of the last event and message taken.
+    events_seen: Rc<Cell<u64>>,
+    messages_seen: Rc<Cell<u64>>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Executor {
+    pub fn new() -> Self {
+        return Self {
+            tasks: Vec::new(),
+            shared: Rc::new(RefCell::new(Shared::default())),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Executor {
+    pub fn signals(&self) -> Signals {
+        return Signals {
+            shared: self.shared.clone(),
+            events_seen: Rc::new(Cell::new(0)),
+            messages_seen: Rc::new(Cell::new(0)),
+        };
+    }
+
+    pub fn spawn<F: Future<Output = ()> + 'static>(&mut self, task: F) {
+        self.tasks.push(Box::pin(task));
+    }
+
+    /// Description
+    /// -----------
+    /// Run every task until it next waits. Should be called once per tick.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `events` - Radar events since the last tick.
+    /// * `messages` - Radio messages received since the last tick.
+    ///
+    pub fn poll(&mut self, events: Vec<Event<'static>>, messages: Vec<RadioMessage>) {
+        {
+            let mut shared = self.shared.borrow_mut();
+            let first = shared.sequence + 1;
+            shared.sequence += (events.len() + messages.len()) as u64;
+
+            shared.events = (first..).zip(events).collect();
+            shared.messages = (first + shared.events.len() as u64..)
+                .zip(messages)
+                .collect();
+        }
+
+        let waker = Waker::from(Arc::new(Noop));
+        let mut context = Context::from_waker(&waker);
+
+        self.tasks
+            .retain_mut(|task| task.as_mut().poll(&mut context).is_pending());
+
+        let mut shared = self.shared.borrow_mut();
+        shared.tick = shared.tick.wrapping_add(1);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Signals {
+    /// Description
+    /// -----------
+    /// Wait until the next tick.
+    ///
+    pub fn next_tick(&self) -> WaitTicks {
+        return self.wait_ticks(1);
+    }
+
+    /// Description
+    /// -----------
+    /// Wait for a number of ticks. Waiting for 0 ticks returns straight away.
+    ///
+    pub fn wait_ticks(&self, ticks: u32) -> WaitTicks {
+        return WaitTicks {
+            shared: self.shared.clone(),
+            ticks,
+            start: None,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Wait for a radar event matching a filter. Only events from the tick the future's polled on
+    /// are considered.
+    ///
+    pub fn radar_event<F: Fn(&Event) -> bool>(&self, filter: F) -> RadarEvent<F> {
+        return RadarEvent {
+            signals: self.clone(),
+            filter,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Wait for a radio message matching a filter. Only messages from the tick the future's
+    /// polled on are considered.
+    ///
+    pub fn radio_message<F: Fn(&RadioMessage) -> bool>(&self, filter: F) -> RadioMessageEvent<F> {
+        return RadioMessageEvent {
+            signals: self.clone(),
+            filter,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Signals {
+    /// Description
+    /// -----------
+    /// Take the first item not already seen that matches a filter.
+    ///
+    fn take<T: Clone, F: Fn(&T) -> bool>(
+        seen: &Cell<u64>,
+        items: &[(u64, T)],
+        filter: &F,
+    ) -> Option<T> {
+        let (number, item) = items
+            .iter()
+            .find(|(number, item)| *number > seen.get() && filter(item))?;
+
+        seen.set(*number);
+        return Some(item.clone());
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+pub struct WaitTicks {
+    shared: Rc<RefCell<Shared>>,
+    ticks: u32,
+    start: Option<u32>,
+}
+
+impl Future for WaitTicks {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<()> {
+        let tick = self.shared.borrow().tick;
+        let start = *self.start.get_or_insert(tick);
+
+        return match tick.wrapping_sub(start) >= self.ticks {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+pub struct RadarEvent<F> {
+    signals: Signals,
+    filter: F,
+}
+
+impl<F: Fn(&Event) -> bool + Unpin> Future for RadarEvent<F> {
+    type Output = Event<'static>;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context) -> Poll<Self::Output> {
+        let shared = self.signals.shared.borrow();
+        return match Signals::take(&self.signals.events_seen, &shared.events, &self.filter) {
+            Some(event) => Poll::Ready(event),
+            None => Poll::Pending,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+pub struct RadioMessageEvent<F> {
+    signals: Signals,
+    filter: F,
+}
+
+impl<F: Fn(&RadioMessage) -> bool + Unpin> Future for RadioMessageEvent<F> {
+    type Output = RadioMessage;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context) -> Poll<Self::Output> {
+        let shared = self.signals.shared.borrow();
+        return match Signals::take(&self.signals.messages_seen, &shared.messages, &self.filter) {
+            Some(message) => Poll::Ready(message),
+            None => Poll::Pending,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::radio::{FleetOrder, Order};
+
+    use super::*;
+
+    #[test]
+    fn test_wait_ticks() {
+        let mut executor = Executor::new();
+        let signals = executor.signals();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let task_log = log.clone();
+        executor.spawn(async move {
+            task_log.borrow_mut().push(signals.shared.borrow().tick);
+            signals.next_tick().await;
+            task_log.borrow_mut().push(signals.shared.borrow().tick);
+            signals.wait_ticks(3).await;
+            task_log.borrow_mut().push(signals.shared.borrow().tick);
+            signals.wait_ticks(0).await;
+            task_log.borrow_mut().push(signals.shared.borrow().tick);
+        });
+
+        for _ in 0..5 {
+            executor.poll(Vec::new(), Vec::new());
+        }
+
+        assert_eq!(*log.borrow(), [0, 1, 4, 4]);
+        assert!(executor.tasks.is_empty());
+    }
+
+    #[test]
+    fn test_radar_event() {
+        let mut executor = Executor::new();
+        let signals = executor.signals();
+        let found = Rc::new(RefCell::new(Vec::new()));
+
+        let task_found = found.clone();
+        executor.spawn(async move {
+            for _ in 0..3 {
+                let event = signals
+                    .radar_event(|event| matches!(event, Event::ContactFound(_)))
+                    .await;
+                task_found.borrow_mut().push(event);
+            }
+        });
+
+        executor.poll(vec![Event::ContactLost(1)], Vec::new());
+        assert_eq!(*found.borrow(), []);
+
+        // Events are only taken once, even when waiting again on the same tick.
+        let events = vec![
+            Event::ContactFound(2),
+            Event::ContactLost(1),
+            Event::ContactFound(3),
+        ];
+        executor.poll(events, Vec::new());
+        assert_eq!(
+            *found.borrow(),
+            [Event::ContactFound(2), Event::ContactFound(3)]
+        );
+
+        executor.poll(vec![Event::ContactFound(4)], Vec::new());
+        assert_eq!(found.borrow().len(), 3);
+        assert!(executor.tasks.is_empty());
+    }
+
+    #[test]
+    fn test_radio_message() {
+        let mut executor = Executor::new();
+        let signals = executor.signals();
+        let received = Rc::new(RefCell::new(None));
+
+        let task_received = received.clone();
+        executor.spawn(async move {
+            let message = signals
+                .radio_message(|message| matches!(message, RadioMessage::FleetOrder(_)))
+                .await;
+            *task_received.borrow_mut() = Some(message);
+        });
+
+        executor.poll(Vec::new(), Vec::new());
+        assert_eq!(*received.borrow(), None);
+
+        let order = RadioMessage::FleetOrder(FleetOrder {
+            commander: 1,
+            order: Order::Engage,
+        });
+        executor.poll(Vec::new(), vec![order.clone()]);
+        assert_eq!(*received.borrow(), Some(order));
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,618 @@
+use std::collections::BTreeMap;
+
+use oort_api::prelude::*;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Result of running a node for one tick.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Status {
+    Success,
+    Failure,
+    Running,
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A value shared between nodes through the blackboard.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Entry {
+    Contact(usize),
+    Vector(Vec2),
+}
+
+/// Description
+/// -----------
+/// Memory shared by every node in a tree. Nodes use it to pass results on to the nodes that run
+/// after them, e.g. the target one node acquires for another to engage.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Blackboard {
+    tick: u32,
+    entries: BTreeMap<&'static str, Entry>,
+}
+
+impl Blackboard {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Description
+    /// -----------
+    /// Return the number of times the tree has been run.
+    ///
+    pub fn tick(&self) -> u32 {
+        return self.tick;
+    }
+
+    pub fn get(&self, key: &'static str) -> Option<Entry> {
+        return self.entries.get(key).copied();
+    }
+
+    pub fn set(&mut self, key: &'static str, entry: Entry) {
+        self.entries.insert(key, entry);
+    }
+
+    pub fn remove(&mut self, key: &'static str) -> Option<Entry> {
+        return self.entries.remove(key);
+    }
+
+    pub fn contact(&self, key: &'static str) -> Option<usize> {
+        return match self.get(key)? {
+            Entry::Contact(id) => Some(id),
+            _ => None,
+        };
+    }
+
+    pub fn vector(&self, key: &'static str) -> Option<Vec2> {
+        return match self.get(key)? {
+            Entry::Vector(vector) => Some(vector),
+            _ => None,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A node in a behaviour tree, run once per tick against the ship it controls.
+///
+pub trait Node<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status;
+
+    /// Description
+    /// -----------
+    /// Forget any progress made. Called when a running node is abandoned by it's parent.
+    ///
+    fn reset(&mut self) {}
+}
+
+/// Description
+/// -----------
+/// Boxed nodes so that trees can be built from nodes of different types.
+///
+pub type Child<C> = Box<dyn Node<C>>;
+
+type ConditionFn<C> = dyn Fn(&C, &Blackboard) -> bool;
+type ActionFn<C> = dyn FnMut(&mut C, &mut Blackboard) -> Status;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Root of a behaviour tree along with it's blackboard.
+///
+pub struct BehaviourTree<C> {
+    root: Child<C>,
+    blackboard: Blackboard,
+}
+
+impl<C> BehaviourTree<C> {
+    pub fn new(root: Child<C>) -> Self {
+        return Self {
+            root,
+            blackboard: Blackboard::new(),
+        };
+    }
+
+    pub fn blackboard(&self) -> &Blackboard {
+        return &self.blackboard;
+    }
+
+    /// Description
+    /// -----------
+    /// Run the tree for one tick. A tree that finishes starts again from the top the next time
+    /// it's run.
+    ///
+    pub fn tick(&mut self, context: &mut C) -> Status {
+        let status = self.root.tick(context, &mut self.blackboard);
+        self.blackboard.tick = self.blackboard.tick.wrapping_add(1);
+
+        return status;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Run children in order until one fails. Succeeds once every child has succeeded. A running
+/// child is picked up again on the next tick rather than re-running the children before it.
+///
+pub struct Sequence<C> {
+    children: Vec<Child<C>>,
+    current: usize,
+}
+
+impl<C> Sequence<C> {
+    pub fn new(children: Vec<Child<C>>) -> Self {
+        return Self {
+            children,
+            current: 0,
+        };
+    }
+}
+
+impl<C> Node<C> for Sequence<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        while let Some(child) = self.children.get_mut(self.current) {
+            match child.tick(context, blackboard) {
+                Status::Success => self.current += 1,
+                Status::Running => return Status::Running,
+                Status::Failure => {
+                    self.reset();
+                    return Status::Failure;
+                }
+            }
+        }
+
+        self.reset();
+        return Status::Success;
+    }
+
+    fn reset(&mut self) {
+        self.children.iter_mut().for_each(|child| child.reset());
+        self.current = 0;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Run children in order until one succeeds. Fails once every child has failed. A running child
+/// is picked up again on the next tick.
+///
+/// The first child is tried again every tick so that higher priority behaviours can interrupt
+/// lower priority ones.
+///
+pub struct Selector<C> {
+    children: Vec<Child<C>>,
+    current: usize,
+}
+
+impl<C> Selector<C> {
+    pub fn new(children: Vec<Child<C>>) -> Self {
+        return Self {
+            children,
+            current: 0,
+        };
+    }
+}
+
+impl<C> Node<C> for Selector<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        for index in 0..self.children.len() {
+            match self.children[index].tick(context, blackboard) {
+                Status::Failure => (),
+                status => {
+                    // Abandon a lower priority child that was running.
+                    if self.current != index {
+                        self.children[self.current].reset();
+                        self.current = index;
+                    }
+
+                    if status == Status::Success {
+                        self.reset();
+                    }
+
+                    return status;
+                }
+            }
+        }
+
+        self.reset();
+        return Status::Failure;
+    }
+
+    fn reset(&mut self) {
+        self.children.iter_mut().for_each(|child| child.reset());
+        self.current = 0;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Run every child each tick. Succeeds once `required` children have succeeded and fails once
+/// that's no longer possible.
+///
+pub struct Parallel<C> {
+    children: Vec<Child<C>>,
+    required: usize,
+}
+
+impl<C> Parallel<C> {
+    pub fn new(children: Vec<Child<C>>, required: usize) -> Self {
+        return Self { children, required };
+    }
+}
+
+impl<C> Node<C> for Parallel<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let mut successes = 0;
+        let mut failures = 0;
+
+        for child in self.children.iter_mut() {
+            match child.tick(context, blackboard) {
+                Status::Success => successes += 1,
+                Status::Failure => failures += 1,
+                Status::Running => (),
+            }
+        }
+
+        let status = if successes >= self.required {
+            Status::Success
+        } else if self.children.len() - failures < self.required {
+            Status::Failure
+        } else {
+            Status::Running
+        };
+
+        if status != Status::Running {
+            self.reset();
+        }
+
+        return status;
+    }
+
+    fn reset(&mut self) {
+        self.children.iter_mut().for_each(|child| child.reset());
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Ways a decorator can change how it's child runs.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Decoration {
+    /// Swap success and failure.
+    #[cfg_attr(not(test), allow(dead_code))]
+    Invert,
+
+    /// Succeed whenever the child finishes, even if it failed.
+    #[cfg_attr(not(test), allow(dead_code))]
+    Succeed,
+
+    /// Run the child again each time it succeeds, until it fails.
+    #[cfg_attr(not(test), allow(dead_code))]
+    UntilFailure,
+
+    /// Fail if the child is still running after this many ticks.
+    Timeout(u32),
+
+    /// Fail without running the child for this many ticks after it last finished.
+    Cooldown(u32),
+}
+
+/// Description
+/// -----------
+/// Wraps a single child and changes how it runs.
+///
+pub struct Decorator<C> {
+    child: Child<C>,
+    decoration: Decoration,
+
+    /// Tick the child started running on, or last finished on for a cooldown.
+    since: Option<u32>,
+}
+
+impl<C> Decorator<C> {
+    pub fn new(decoration: Decoration, child: Child<C>) -> Self {
+        return Self {
+            child,
+            decoration,
+            since: None,
+        };
+    }
+}
+
+impl<C> Node<C> for Decorator<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        let tick = blackboard.tick();
+
+        return match self.decoration {
+            Decoration::Invert => match self.child.tick(context, blackboard) {
+                Status::Success => Status::Failure,
+                Status::Failure => Status::Success,
+                Status::Running => Status::Running,
+            },
+
+            Decoration::Succeed => match self.child.tick(context, blackboard) {
+                Status::Running => Status::Running,
+                _ => Status::Success,
+            },
+
+            Decoration::UntilFailure => match self.child.tick(context, blackboard) {
+                Status::Failure => Status::Success,
+                _ => Status::Running,
+            },
+
+            Decoration::Timeout(ticks) => {
+                let start = *self.since.get_or_insert(tick);
+                if tick.wrapping_sub(start) >= ticks {
+                    self.reset();
+                    return Status::Failure;
+                }
+
+                let status = self.child.tick(context, blackboard);
+                if status != Status::Running {
+                    self.since = None;
+                }
+
+                status
+            }
+
+            Decoration::Cooldown(ticks) => {
+                if self.since.is_some_and(|end| tick.wrapping_sub(end) < ticks) {
+                    return Status::Failure;
+                }
+
+                let status = self.child.tick(context, blackboard);
+                if status != Status::Running {
+                    self.since = Some(tick);
+                }
+
+                status
+            }
+        };
+    }
+
+    fn reset(&mut self) {
+        self.child.reset();
+
+        // Cooldowns carry on regardless.
+        if !matches!(self.decoration, Decoration::Cooldown(_)) {
+            self.since = None;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Leaf that checks a condition, succeeding if it holds and failing otherwise.
+///
+pub struct Condition<C> {
+    condition: Box<ConditionFn<C>>,
+}
+
+impl<C> Condition<C> {
+    pub fn new<F: Fn(&C, &Blackboard) -> bool + 'static>(condition: F) -> Self {
+        return Self {
+            condition: Box::new(condition),
+        };
+    }
+}
+
+impl<C> Node<C> for Condition<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        return match (self.condition)(context, blackboard) {
+            true => Status::Success,
+            false => Status::Failure,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Leaf that runs a closure, for one-off behaviour not worth a node of it's own.
+///
+pub struct Action<C> {
+    action: Box<ActionFn<C>>,
+}
+
+impl<C> Action<C> {
+    pub fn new<F: FnMut(&mut C, &mut Blackboard) -> Status + 'static>(action: F) -> Self {
+        return Self {
+            action: Box::new(action),
+        };
+    }
+}
+
+impl<C> Node<C> for Action<C> {
+    fn tick(&mut self, context: &mut C, blackboard: &mut Blackboard) -> Status {
+        return (self.action)(context, blackboard);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Context recording which leaves ran.
+    type Log = Vec<&'static str>;
+
+    /// Leaf returning a fixed series of statuses, repeating the last one.
+    fn leaf(name: &'static str, statuses: &[Status]) -> Child<Log> {
+        let mut statuses = statuses.to_vec();
+        return Box::new(Action::new(move |log: &mut Log, _: &mut Blackboard| {
+            log.push(name);
+            return match statuses.len() {
+                1 => statuses[0],
+                _ => statuses.remove(0),
+            };
+        }));
+    }
+
+    use Status::*;
+
+    #[test]
+    fn test_sequence() {
+        let mut tree = BehaviourTree::new(Box::new(Sequence::new(vec![
+            leaf("a", &[Success]),
+            leaf("b", &[Running, Success]),
+            leaf("c", &[Success]),
+        ])));
+
+        let mut log = Log::new();
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(log, ["a", "b"]);
+
+        // Picks up where it left off.
+        log.clear();
+        assert_eq!(tree.tick(&mut log), Success);
+        assert_eq!(log, ["b", "c"]);
+
+        let mut tree = BehaviourTree::new(Box::new(Sequence::new(vec![
+            leaf("a", &[Failure]),
+            leaf("b", &[Success]),
+        ])));
+
+        log.clear();
+        assert_eq!(tree.tick(&mut log), Failure);
+        assert_eq!(log, ["a"]);
+    }
+
+    #[test]
+    fn test_selector() {
+        let mut tree = BehaviourTree::new(Box::new(Selector::new(vec![
+            leaf("a", &[Failure, Failure, Running]),
+            leaf("b", &[Running]),
+        ])));
+
+        let mut log = Log::new();
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(log, ["a", "b"]);
+
+        // Higher priority children are checked every tick and can take over.
+        log.clear();
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(log, ["a", "b"]);
+
+        log.clear();
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(log, ["a"]);
+
+        let mut tree = BehaviourTree::new(Box::new(Selector::new(vec![
+            leaf("a", &[Failure]),
+            leaf("b", &[Failure]),
+        ])));
+
+        log.clear();
+        assert_eq!(tree.tick(&mut log), Failure);
+        assert_eq!(log, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_parallel() {
+        let mut tree = BehaviourTree::new(Box::new(Parallel::new(
+            vec![leaf("a", &[Running, Success]), leaf("b", &[Success])],
+            2,
+        )));
+
+        let mut log = Log::new();
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(tree.tick(&mut log), Success);
+        assert_eq!(log, ["a", "b", "a", "b"]);
+
+        let mut tree = BehaviourTree::new(Box::new(Parallel::new(
+            vec![leaf("a", &[Failure]), leaf("b", &[Running])],
+            2,
+        )));
+        assert_eq!(tree.tick(&mut log), Failure);
+
+        let mut tree = BehaviourTree::new(Box::new(Parallel::new(
+            vec![leaf("a", &[Failure]), leaf("b", &[Success])],
+            1,
+        )));
+        assert_eq!(tree.tick(&mut log), Success);
+    }
+
+    #[test]
+    fn test_decorator() {
+        let mut log = Log::new();
+
+        let mut tree = BehaviourTree::new(Box::new(Decorator::new(
+            Decoration::Invert,
+            leaf("a", &[Success]),
+        )));
+        assert_eq!(tree.tick(&mut log), Failure);
+
+        let mut tree = BehaviourTree::new(Box::new(Decorator::new(
+            Decoration::Succeed,
+            leaf("a", &[Failure]),
+        )));
+        assert_eq!(tree.tick(&mut log), Success);
+
+        let mut tree = BehaviourTree::new(Box::new(Decorator::new(
+            Decoration::UntilFailure,
+            leaf("a", &[Success, Success, Failure]),
+        )));
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(tree.tick(&mut log), Success);
+
+        let mut tree = BehaviourTree::new(Box::new(Decorator::new(
+            Decoration::Timeout(2),
+            leaf("a", &[Running]),
+        )));
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(tree.tick(&mut log), Running);
+        assert_eq!(tree.tick(&mut log), Failure);
+
+        let mut tree = BehaviourTree::new(Box::new(Decorator::new(
+            Decoration::Cooldown(2),
+            leaf("a", &[Success]),
+        )));
+        assert_eq!(tree.tick(&mut log), Success);
+        assert_eq!(tree.tick(&mut log), Failure);
+        assert_eq!(tree.tick(&mut log), Success);
+    }
+
+    #[test]
+    fn test_blackboard() {
+        let mut tree = BehaviourTree::new(Box::new(Sequence::new(vec![
+            Box::new(Action::new(|_: &mut Log, blackboard: &mut Blackboard| {
+                blackboard.set("target", Entry::Contact(4));
+                return Success;
+            })),
+            Box::new(Condition::new(|_: &Log, blackboard: &Blackboard| {
+                blackboard.contact("target") == Some(4)
+            })),
+        ])));
+
+        assert_eq!(tree.tick(&mut Log::new()), Success);
+        assert_eq!(tree.blackboard().get("target"), Some(Entry::Contact(4)));
+        assert_eq!(tree.blackboard().vector("target"), None);
+        assert_eq!(tree.blackboard().tick(), 1);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -1,15 +1,132 @@
 use oort_api::prelude::*;
 
-use super::math::kinematics::{Acceleration, AngularVelocity, Position, Velocity};
+use super::{
+    math::kinematics::{AngularVelocity, Position, Velocity},
+    ship::stats::MaxAcceleration,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Residual acceleration below which thrust is counted as delivered in full.
+const SATURATION_TOLERANCE: f64 = 1e-3; // m/s^2
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// An acceleration split between what the ship's thrusters can deliver and what's left over.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Thrust {
+    /// Acceleration the thrusters can deliver, in the world frame.
+    pub acceleration: Vec2,
+
+    /// Part of the requested acceleration that couldn't be delivered, in the world frame.
+    pub residual: Vec2,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Thrust {
+    /// Description
+    /// -----------
+    /// Find the closest acceleration to the one requested that's within a ship's limits.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `acceleration` - Requested acceleration in the world frame.
+    /// * `heading` - Heading of the ship.
+    /// * `limits` - Ship's acceleration limits along and across it's heading.
+    ///
+    pub fn allocate(acceleration: Vec2, heading: f64, limits: &MaxAcceleration) -> Self {
+        // The limits apply independently along and across the ship's heading, so clamping each
+        // gives the closest achievable acceleration.
+        let local = acceleration.rotate(-heading);
+        let forward = local.x.clamp(-limits.reverse, limits.forward);
+        let lateral = local.y.clamp(-limits.lateral, limits.lateral);
+
+        let achievable = vec2(forward, lateral).rotate(heading);
+        return Self {
+            acceleration: achievable,
+            residual: acceleration - achievable,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return whether the thrusters couldn't deliver all of the requested acceleration.
+    ///
+    pub fn is_saturated(&self) -> bool {
+        return self.residual.length() > SATURATION_TOLERANCE;
+    }
+}
 
 ////////////////////////////////////////////////////////////////
 
 pub trait Translation: Position {
-    fn set_acceleration(&mut self, acceleration: Vec2);
+    /// Description
+    /// -----------
+    /// Accelerate as close to the requested acceleration as the ship's thrusters allow.
+    ///
+    /// Returns
+    /// -------
+    /// What was delivered and what was left over.
+    ///
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust;
 
-    fn accelerate_towards<T: Position>(&self, target: &T) {
+    /// Description
+    /// -----------
+    /// Accelerate towards a point as hard as the ship's thrusters allow.
+    ///
+    fn accelerate_towards<T: Position>(&mut self, target: &T) -> Thrust {
         let target_vector = target.position() - self.position();
-        accelerate(target_vector);
+        return self.set_acceleration(target_vector);
+    }
+
+    /// Description
+    /// -----------
+    /// Match position and velocity with a moving station. Closes on the station no faster than
+    /// `max_closing_speed` and starts braking in time to arrive without overshooting.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `station` - Station to hold.
+    /// * `max_acceleration` - Acceleration available in any direction.
+    /// * `max_closing_speed` - Fastest to approach the station at, relative to it's velocity.
+    ///
+    fn hold_station<T: Velocity>(
+        &mut self,
+        station: &T,
+        max_acceleration: f64,
+        max_closing_speed: f64,
+    ) where
+        Self: Velocity,
+    {
+        let offset = station.position() - self.position();
+        let distance = offset.length();
+
+        // v^2 = u^2 + 2as, the fastest we can close from and still stop in time. Settle
+        // proportionally over the last few meters rather than chattering either side of the
+        // station.
+        let closing_speed = f64::sqrt(2.0 * max_acceleration * distance)
+            .min(distance)
+            .min(max_closing_speed);
+
+        let direction = if distance > 0.0 {
+            offset / distance
+        } else {
+            vec2(0.0, 0.0)
+        };
+
+        let velocity = station.velocity() + (direction * closing_speed);
+        let acceleration = (velocity - self.velocity()) / TICK_LENGTH;
+        let acceleration = if acceleration.length() > max_acceleration {
+            acceleration.normalize() * max_acceleration
+        } else {
+            acceleration
+        };
+
+        self.set_acceleration(acceleration);
     }
 }
 
@@ -18,56 +135,284 @@ pub trait Translation: Position {
 pub trait Rotation: AngularVelocity + Velocity + Sized {
     fn set_angular_acceleration(&mut self, angular_acceleration: f64);
 
-    // Create implementation for heading, position, vessel and tracked vessel?
-    // If target is moving, we don't want final velocity to be 0.
+    /// Description
+    /// -----------
+    /// Fastest the ship can change it's angular velocity.
+    ///
+    fn max_angular_acceleration(&self) -> f64;
+
+    /// Description
+    /// -----------
+    /// Turn to face a fixed point.
+    ///
     fn turn_to_face<T: Position>(&mut self, target: &T) {
-        let target_bearing = self.relative_bearing_to(target);
+        let bearing = self.relative_bearing_to(target);
+        self.align(bearing, 0.0);
+    }
+
+    fn turn_to_heading(&mut self, heading: f64) {
+        let bearing = angle_diff(self.heading(), heading);
+        self.align(bearing, 0.0);
+    }
+
+    /// Description
+    /// -----------
+    /// Turn to face a moving target, settling into turning at the rate it's bearing changes so
+    /// that we stay on it.
+    ///
+    fn turn_to_track<T: Velocity>(&mut self, target: &T) {
+        let bearing = self.relative_bearing_to(target);
+        self.align(bearing, target.orbital_velocity_to(self));
+    }
 
-        let velocity = 2.0 * target_bearing.abs().sqrt();
-        let velocity = if target_bearing < 0.0 {
-            -velocity
+    /// Description
+    /// -----------
+    /// Bring the bearing of an aim point to zero in the least time, without overshooting. Turns
+    /// towards it at full acceleration and brakes at full acceleration once it's only just
+    /// possible to stop on it.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `bearing` - Bearing of the aim point relative to our heading.
+    /// * `bearing_rate` - Rate the aim point's bearing is changing at.
+    ///
+    fn align(&mut self, bearing: f64, bearing_rate: f64) {
+        let max_acceleration = self.max_angular_acceleration();
+
+        // Fastest we can close on the bearing and still stop on it, allowing for a tick passing
+        // before the next command. Solves v*t + v^2/2a = s for v.
+        let step = max_acceleration * TICK_LENGTH;
+        let closing = f64::sqrt(step.powf(2.0) + (2.0 * max_acceleration * bearing.abs())) - step;
+
+        let velocity = bearing_rate + closing.copysign(bearing);
+        let acceleration = (velocity - self.angular_velocity()) / TICK_LENGTH;
+        self.set_angular_acceleration(acceleration.clamp(-max_acceleration, max_acceleration));
+    }
+
+    /// Description
+    /// -----------
+    /// Return the least time it would take to face a target and match the rate it's bearing is
+    /// changing at.
+    ///
+    fn time_to_align<T: Velocity>(&self, target: &T) -> f64 {
+        let bearing = self.relative_bearing_to(target);
+        let closing = target.orbital_velocity_to(self) - self.angular_velocity();
+        return time_to_zero(bearing, closing, self.max_angular_acceleration());
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return the least time to bring an error and it's rate of change to zero together, using at
+/// most the given acceleration.
+///
+/// Parmaters
+/// ---------
+/// * `error` - Distance to go.
+/// * `rate` - Rate the error is changing at.
+/// * `max_acceleration` - Largest change in the rate available.
+///
+fn time_to_zero(error: f64, rate: f64, max_acceleration: f64) -> f64 {
+    if max_acceleration <= 0.0 {
+        return if error == 0.0 && rate == 0.0 {
+            0.0
         } else {
-            velocity
+            f64::INFINITY
         };
+    }
 
-        let acceleration = (velocity - self.angular_velocity()) / TICK_LENGTH;
-        self.set_angular_acceleration(acceleration);
+    // Where we'd come to rest if we braked now. Past zero means we have to brake and turn back,
+    // otherwise we can push on towards zero before braking.
+    let stop = error + ((rate * rate.abs()) / (2.0 * max_acceleration));
+    let (error, rate) = if stop < 0.0 {
+        (-error, -rate)
+    } else {
+        (error, rate)
+    };
+
+    let peak = f64::sqrt((max_acceleration * error) + (rate.powf(2.0) / 2.0));
+    return (rate + (2.0 * peak)) / max_acceleration;
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::math::kinematics::{Heading, KinematicModel};
+
+    /// Description
+    /// -----------
+    /// A body that turns under a limited torque, standing in for a ship.
+    ///
+    struct Body {
+        heading: f64,
+        angular_velocity: f64,
+        max_angular_acceleration: f64,
+        angular_acceleration: f64,
+    }
+
+    impl Position for Body {
+        fn position(&self) -> Vec2 {
+            return vec2(0.0, 0.0);
+        }
+    }
+
+    impl Velocity for Body {
+        fn velocity(&self) -> Vec2 {
+            return vec2(0.0, 0.0);
+        }
+    }
+
+    impl Heading for Body {
+        fn heading(&self) -> f64 {
+            return self.heading;
+        }
     }
 
-    fn turn_to_track<T: Acceleration>(&mut self, target: &T) {
-        let target_bearing = self.relative_bearing_to(target);
+    impl AngularVelocity for Body {
+        fn angular_velocity(&self) -> f64 {
+            return self.angular_velocity;
+        }
+    }
+
+    impl Rotation for Body {
+        fn set_angular_acceleration(&mut self, angular_acceleration: f64) {
+            self.angular_acceleration = angular_acceleration;
+        }
 
-        // Find the equation giving target bearing.
-        // Find time to intercept.
+        fn max_angular_acceleration(&self) -> f64 {
+            return self.max_angular_acceleration;
+        }
+    }
 
-        // Given a maximum deceleration, find the maximum velocity we could be at in our current
-        // position to not overshoot.
-        // v^2 = u^2 + 2as where a = 2.0 or -2.0 depending on whether bearing is + or -.
-        // sqrt(v^2 - 2as)
-        let decel = if target_bearing < 0.0 { 2.0 } else { -2.0 };
-        // let decel = decel - target_acceleration;
-        let max_velocity = f64::sqrt(
-            (target.orbital_velocity_to(self).powf(2.0) - (2.0 * decel * target_bearing)).abs(),
+    impl Body {
+        fn new(max_angular_acceleration: f64) -> Self {
+            return Self {
+                heading: 0.0,
+                angular_velocity: 0.0,
+                max_angular_acceleration,
+                angular_acceleration: 0.0,
+            };
+        }
+
+        fn step(&mut self) {
+            let limit = self.max_angular_acceleration;
+            let acceleration = self.angular_acceleration.clamp(-limit, limit);
+            self.angular_velocity += acceleration * TICK_LENGTH;
+            self.heading += self.angular_velocity * TICK_LENGTH;
+        }
+    }
+
+    /// Target moving in a circle around the origin.
+    fn orbiting(radius: f64, rate: f64, time: f64) -> KinematicModel {
+        let angle = PI / 2.0 + (rate * time);
+        let radial = vec2(1.0, 0.0).rotate(angle);
+        return KinematicModel::new(
+            radial * radius,
+            radial.rotate(PI / 2.0) * (radius * rate),
+            radial * -(radius * rate.powf(2.0)),
         );
+    }
 
-        let max_velocity = if target_bearing.is_sign_negative() {
-            -max_velocity
-        } else {
-            max_velocity
-        };
+    #[test]
+    fn test_allocate() {
+        let limits = MaxAcceleration::FIGHTER;
+
+        // Within limits in any direction.
+        let thrust = Thrust::allocate(vec2(0.0, 20.0), 0.0, &limits);
+        assert_eq!(thrust.acceleration, vec2(0.0, 20.0));
+        assert!(!thrust.is_saturated());
+
+        // Limits turn with the ship.
+        let thrust = Thrust::allocate(vec2(0.0, 50.0), PI / 2.0, &limits);
+        assert!(thrust.acceleration.distance(vec2(0.0, 50.0)) < 1e-9);
+        assert!(!thrust.is_saturated());
+
+        // Each axis is limited separately.
+        let thrust = Thrust::allocate(vec2(100.0, -100.0), 0.0, &limits);
+        assert_eq!(thrust.acceleration, vec2(60.0, -30.0));
+        assert_eq!(thrust.residual, vec2(40.0, -70.0));
+        assert!(thrust.is_saturated());
+
+        let thrust = Thrust::allocate(vec2(-100.0, 0.0), 0.0, &limits);
+        assert_eq!(thrust.acceleration, vec2(-30.0, 0.0));
+
+        // Residual makes up the rest of the request.
+        let request = vec2(-80.0, 45.0);
+        let thrust = Thrust::allocate(request, 1.0, &MaxAcceleration::MISSILE);
+        assert!((thrust.acceleration + thrust.residual).distance(request) < 1e-9);
+        let local = thrust.acceleration.rotate(-1.0);
+        assert!(local.x >= -1e-9 && local.y.abs() <= 100.0 + 1e-9);
+    }
+
+    #[test]
+    fn test_time_to_zero() {
+        // From rest, accelerate half way and brake the rest.
+        assert!((time_to_zero(1.0, 0.0, 2.0) - 2.0 * f64::sqrt(0.5)).abs() < 1e-9);
+        assert!((time_to_zero(-1.0, 0.0, 2.0) - 2.0 * f64::sqrt(0.5)).abs() < 1e-9);
+
+        // Already moving on the braking curve.
+        assert!((time_to_zero(1.0, -2.0, 2.0) - 1.0).abs() < 1e-9);
+
+        // Moving the wrong way first has to be undone.
+        assert!(time_to_zero(1.0, 1.0, 2.0) > time_to_zero(1.0, 0.0, 2.0));
+
+        assert_eq!(time_to_zero(0.0, 0.0, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_turn_to_face() {
+        let mut body = Body::new(PI);
+        let target = KinematicModel::new(vec2(0.0, 1000.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+
+        let expected = body.time_to_align(&target);
+        let mut settled = None;
+        for tick in 0..1000 {
+            body.turn_to_face(&target);
+            body.step();
 
-        // v = u + at
-        // a = (v - u) / t
-        // let acceleration = (velocity - self.angular_velocity()) / TICK_LENGTH;
-        let acceleration = (max_velocity - self.angular_velocity()) / TICK_LENGTH;
-        self.set_angular_acceleration(acceleration);
+            // Never turns past the target.
+            let bearing = body.relative_bearing_to(&target);
+            assert!(bearing > -1e-3, "overshot by {bearing} on tick {tick}");
 
-        debug!("----------------");
-        debug!("target bearing:   {}", target_bearing);
-        debug!("target velocity:  {}", max_velocity);
-        debug!("orb acceleration: {}", target.orbital_acceleration_to(self));
-        debug!("current velocity: {}", self.angular_velocity());
-        debug!("acceleration:     {}", acceleration);
-        debug!("----------------");
+            if settled.is_none() && bearing.abs() < 1e-3 && body.angular_velocity.abs() < 1e-2 {
+                settled = Some(tick as f64 * TICK_LENGTH);
+            }
+        }
+
+        // Close to the best possible time.
+        let settled = settled.unwrap();
+        assert!(settled >= expected - TICK_LENGTH);
+        assert!(settled < expected + 0.5, "{settled} vs {expected}");
+        assert!(body.relative_bearing_to(&target).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_turn_to_track() {
+        let mut body = Body::new(PI);
+        let rate = 0.2;
+
+        for tick in 0..1000 {
+            let time = tick as f64 * TICK_LENGTH;
+            let target = orbiting(1000.0, rate, time);
+            body.turn_to_track(&target);
+            body.step();
+
+            let target = orbiting(1000.0, rate, time + TICK_LENGTH);
+            let bearing = body.relative_bearing_to(&target);
+            assert!(bearing > -1e-3, "overshot by {bearing} on tick {tick}");
+        }
+
+        // Sat on the target, turning with it.
+        let target = orbiting(1000.0, rate, 1000.0 * TICK_LENGTH);
+        assert!(body.relative_bearing_to(&target).abs() < 1e-3);
+        assert!((body.angular_velocity - rate).abs() < 1e-3);
+        assert!(body.time_to_align(&target) < 0.1);
     }
 }
+
+////////////////////////////////////////////////////////////////
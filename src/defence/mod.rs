@@ -0,0 +1,12 @@
+//! Description
+//! -----------
+//! Protecting a ship from incoming missiles.
+//!
+
+mod point;
+
+// Imports
+use super::{error, math, radar, ship};
+
+// Exports
+pub use self::point::{PointDefence, Response};
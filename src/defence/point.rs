@@ -0,0 +1,261 @@
+use oort_api::prelude::*;
+
+use super::{
+    error,
+    math::{
+        kinematics::{KinematicModel, Position, Velocity},
+        FiringSolution,
+    },
+    radar::{board::ContactBoard, CompositeRadar},
+    ship::stats::Dimensions,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Distance from a missile at which it's warhead can hurt us.
+const BLAST_RADIUS: f64 = 200.0; // m
+
+/// Time to impact under which a missile is shot at in place of the primary target.
+const SWITCH_TIME: f64 = 5.0; // s
+
+/// Time allowed to bring the guns round on to a missile.
+const REACTION_TIME: f64 = 0.5; // s
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return the time and distance of closest approach between two vessels, assuming neither
+/// accelerates.
+///
+/// Returns
+/// -------
+/// A negative time if the closest approach has already passed.
+///
+pub fn closest_approach<T: Velocity, U: Velocity>(vessel: &T, other: &U) -> (f64, f64) {
+    let relpos = other.position_relative_to(vessel);
+    let relvel = other.velocity_relative_to(vessel);
+
+    let speed_squared = relvel.dot(relvel);
+    let time = if speed_squared > 0.0 {
+        -relpos.dot(relvel) / speed_squared
+    } else {
+        0.0
+    };
+
+    return (time, (relpos + (relvel * time)).length());
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Incoming missile that will pass close enough to hurt us.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Threat {
+    pub id: usize,
+
+    /// Time until closest approach.
+    pub time_to_impact: f64,
+
+    /// Distance it'll pass us by.
+    pub miss_distance: f64,
+}
+
+/// Description
+/// -----------
+/// What point defence wants the ship to do this tick.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Response {
+    /// Nothing needs dealing with. Carry on with the primary target.
+    Primary,
+
+    /// Shoot at an incoming missile.
+    Defend(usize),
+
+    /// A missile is too close to shoot down. Accelerate in the given direction to get out of it's
+    /// way.
+    Evade(Vec2),
+}
+
+/// Description
+/// -----------
+/// Keeps track of missiles heading for the ship and decides how to deal with them.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct PointDefence {
+    projectile_speed: f64,
+    danger_radius: f64,
+
+    /// Incoming missiles, most urgent first.
+    threats: Vec<Threat>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl PointDefence {
+    /// Description
+    /// -----------
+    /// Parmaters
+    /// ---------
+    /// * `dimensions` - Dimensions of the ship being defended.
+    /// * `projectile_speed` - Speed of the guns' projectiles.
+    ///
+    pub fn new(dimensions: &Dimensions, projectile_speed: f64) -> Self {
+        return Self {
+            projectile_speed,
+            danger_radius: dimensions.longest() + BLAST_RADIUS,
+            threats: Vec::new(),
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Rank every missile on the radar by how soon it'll reach us. Missiles that have passed or
+    /// will miss by a safe distance are ignored.
+    ///
+    pub fn update<T: Velocity>(&mut self, vessel: &T, radar: &CompositeRadar) {
+        self.threats = radar
+            .contacts
+            .iter()
+            .filter(|(_, c)| matches!(c.class(), Class::Missile | Class::Torpedo))
+            .filter_map(|(id, contact)| {
+                let (time_to_impact, miss_distance) = closest_approach(vessel, contact);
+
+                (time_to_impact > 0.0 && miss_distance < self.danger_radius).then_some(Threat {
+                    id: *id,
+                    time_to_impact,
+                    miss_distance,
+                })
+            })
+            .collect();
+
+        self.threats
+            .sort_by(|a, b| a.time_to_impact.total_cmp(&b.time_to_impact));
+    }
+
+    pub fn most_urgent(&self) -> Option<&Threat> {
+        return self.threats.first();
+    }
+
+    /// Description
+    /// -----------
+    /// Return a firing solution on a missile from where it is right now. Missiles manoeuvre too
+    /// hard for their acceleration to be worth leading.
+    ///
+    pub fn solution<T: Velocity>(
+        &self,
+        vessel: &T,
+        radar: &CompositeRadar,
+        id: usize,
+    ) -> Option<FiringSolution> {
+        let contact = radar.contacts.get(id)?;
+        let snapshot = KinematicModel::new(contact.position(), contact.velocity(), vec2(0.0, 0.0));
+
+        // Missiles we can't catch, or that have already gone past, are expected every tick.
+        return match FiringSolution::new(vessel, self.projectile_speed, &snapshot) {
+            Ok(solution) => Some(solution),
+            Err(error::Error::Miss(_)) => None,
+            Err(error) => {
+                error::report(error);
+                None
+            }
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Decide whether to stay on the primary target, shoot down the most urgent missile or get
+    /// out of it's way.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `vessel` - Ship being defended.
+    /// * `radar` - Radar the threats were ranked from.
+    /// * `reload` - Time until the guns can next fire.
+    ///
+    pub fn decide<T: Velocity>(&self, vessel: &T, radar: &CompositeRadar, reload: f64) -> Response {
+        let Some(threat) = self.most_urgent() else {
+            return Response::Primary;
+        };
+
+        if threat.time_to_impact > SWITCH_TIME {
+            return Response::Primary;
+        }
+
+        let Some(missile) = radar.contacts.get(threat.id) else {
+            return Response::Primary;
+        };
+
+        // Only worth shooting at if the rounds can get there before it does.
+        let flight = missile.distance_to(vessel) / self.projectile_speed;
+        if reload + REACTION_TIME + flight < threat.time_to_impact {
+            return Response::Defend(threat.id);
+        }
+
+        return Response::Evade(evasion(vessel, missile));
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return the direction to accelerate in to open up the miss distance of a missile. That's away
+/// from it's line of approach, on whichever side we're already on.
+///
+fn evasion<T: Velocity, U: Velocity>(vessel: &T, missile: &U) -> Vec2 {
+    let relpos = vessel.position_relative_to(missile);
+    let relvel = missile.velocity_relative_to(vessel);
+    let course = relvel.normalize();
+
+    let miss = relpos - (course * relpos.dot(course));
+    if miss.length() > 1.0 {
+        return miss.normalize();
+    }
+
+    // Head on. Either side will do.
+    return vec2(-course.y, course.x);
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_approach() {
+        let vessel = KinematicModel::new(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+
+        // Passes 100m to one side, 10s from now.
+        let missile = KinematicModel::new(vec2(-1000.0, 100.0), vec2(100.0, 0.0), vec2(0.0, 0.0));
+        let (time, distance) = closest_approach(&vessel, &missile);
+        assert!((time - 10.0).abs() < 1e-9);
+        assert!((distance - 100.0).abs() < 1e-9);
+
+        // Already gone past.
+        let missile = KinematicModel::new(vec2(1000.0, 100.0), vec2(100.0, 0.0), vec2(0.0, 0.0));
+        let (time, _) = closest_approach(&vessel, &missile);
+        assert!(time < 0.0);
+    }
+
+    #[test]
+    fn test_evasion() {
+        let vessel = KinematicModel::new(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+
+        // Passing below us. Move up, away from it.
+        let missile = KinematicModel::new(vec2(-1000.0, -50.0), vec2(100.0, 0.0), vec2(0.0, 0.0));
+        let direction = evasion(&vessel, &missile);
+        assert!((direction - vec2(0.0, 1.0)).length() < 1e-9);
+
+        // Head on. Move sideways.
+        let missile = KinematicModel::new(vec2(-1000.0, 0.0), vec2(100.0, 0.0), vec2(0.0, 0.0));
+        let direction = evasion(&vessel, &missile);
+        assert!(direction.dot(vec2(1.0, 0.0)).abs() < 1e-9);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,217 @@
+use oort_api::prelude::*;
+
+use super::{
+    behaviour::RadarEvents,
+    control::{Rotation, Thrust, Translation},
+    math::kinematics::{Acceleration, AngularVelocity, Heading, Position, Velocity},
+    radar::{board::UniqueContactBoard, CompositeRadar},
+    radio::{ChannelManager, Radio as ShipRadio, Transport},
+    ship::{
+        stats::{MaxAcceleration, WeaponStats},
+        weapon::Weapon,
+    },
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Radar and the contact it's been asked to track.
+///
+pub struct Radar {
+    pub radar: CompositeRadar,
+    pub events: RadarEvents,
+
+    /// Contact to track, set by other systems and applied on the next scan.
+    pub track: Option<usize>,
+    pub(super) tracking: Option<usize>,
+}
+
+/// Description
+/// -----------
+/// Radio and the channels it's tuned to.
+///
+pub struct Radio {
+    pub transport: Transport,
+    pub channels: ChannelManager,
+}
+
+/// Description
+/// -----------
+/// Guns and missile launchers, and the contact they're aimed at.
+///
+pub struct Weapons {
+    pub loadout: Vec<Weapon>,
+
+    pub target: Option<usize>,
+}
+
+/// Description
+/// -----------
+/// How the ship is being steered.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Steering {
+    /// Fly towards a point.
+    Approach(Vec2),
+
+    /// Point the guns at a contact.
+    Engage(usize),
+}
+
+/// Description
+/// -----------
+/// Engines and where they're taking us. Other systems set the steering; the navigation system
+/// flies it and records where the guns ended up pointed.
+///
+pub struct Navigation {
+    pub steering: Steering,
+
+    /// Where the guns need to point to hit the target being engaged.
+    pub aim: Option<Vec2>,
+
+    acceleration: Vec2,
+    max_acceleration: MaxAcceleration,
+}
+
+/// Description
+/// -----------
+/// What the ship is trying to do.
+///
+pub struct Behaviour {
+    /// Class of contact to hunt.
+    pub prey: Class,
+    pub target: Option<usize>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Radar {
+    pub fn new() -> Self {
+        return Self {
+            radar: CompositeRadar::new(UniqueContactBoard::new()),
+            events: RadarEvents::new(),
+
+            track: None,
+            tracking: None,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Radio {
+    pub fn new() -> Self {
+        return Self {
+            transport: Transport::new(ShipRadio::new()),
+            channels: ChannelManager::new(id() as u16),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Weapons {
+    pub fn new(loadout: &'static [WeaponStats]) -> Self {
+        return Self {
+            loadout: Weapon::loadout(loadout).collect(),
+
+            target: None,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Speed of the guns' projectiles. Guns are assumed to all fire at the same speed.
+    ///
+    pub fn bullet_speed(&self) -> Option<f64> {
+        return self.loadout.iter().find(|w| w.is_gun()).map(Weapon::speed);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Navigation {
+    pub fn new(max_acceleration: MaxAcceleration) -> Self {
+        return Self {
+            steering: Steering::Approach(vec2(0.0, 0.0)),
+            aim: None,
+
+            acceleration: vec2(0.0, 0.0),
+            max_acceleration,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Behaviour {
+    pub fn new(prey: Class) -> Self {
+        return Self { prey, target: None };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for Navigation {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for Navigation {
+    fn velocity(&self) -> Vec2 {
+        return velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for Navigation {
+    fn acceleration(&self) -> Vec2 {
+        return self.acceleration;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for Navigation {
+    fn heading(&self) -> f64 {
+        return heading();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for Navigation {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Translation for Navigation {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &self.max_acceleration);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Rotation for Navigation {
+    fn set_angular_acceleration(&mut self, acceleration: f64) {
+        torque(acceleration)
+    }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return self.max_acceleration.angular;
+    }
+}
+
+////////////////////////////////////////////////////////////////
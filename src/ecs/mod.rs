@@ -0,0 +1,22 @@
+//! Description
+//! -----------
+//! Entity/component/system layout for ships. Subsystems such as the radar and radio are
+//! components, and systems run over them in a fixed order each tick, passing information on
+//! through the components and events rather than being wired together by hand in each role.
+//!
+//! The default fighter is built this way, as is the experimental interceptor.
+//!
+
+mod components;
+mod systems;
+mod world;
+
+// Imports
+use super::{behaviour, control, draw, error, math, radar, radio, ship};
+
+// Exports
+pub use self::{
+    components::{Behaviour, Navigation, Radar, Radio, Weapons},
+    systems::{AdjustRadar, FireWeapons, Hunt, Navigate, ReceiveRadio, ScanRadar, TransmitRadio},
+    world::{Entity, Schedule, System, World, WorldEvent},
+};
@@ -0,0 +1,291 @@
+use oort_api::prelude::*;
+
+use super::{
+    behaviour::Event,
+    components::Steering,
+    control::{Rotation, Translation},
+    draw, error,
+    math::{
+        kinematics::{Heading, KinematicModel, Position, Velocity},
+        FiringSolution,
+    },
+    radar::{
+        board::ContactBoard,
+        contacts::{Contact, RadarContact},
+    },
+    radio::{MissileDatalink, RadioMessage, Tuning, BROADCAST},
+    ship::weapon::{Outcome, Weapon},
+    world::{System, World, WorldEvent},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Bearing within which the guns are fired.
+const GUN_BEARING: f64 = 0.02; // rad
+
+/// Bearing within which missiles are launched.
+const MISSILE_BEARING: f64 = PI / 4.0; // rad
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Points the tracking radar at the requested contact, scans, and raises events for changes to
+/// the contact board.
+///
+pub struct ScanRadar;
+
+impl System for ScanRadar {
+    fn run(&mut self, world: &mut World) {
+        for (entity, radar) in world.radar.iter_mut() {
+            if radar.track != radar.tracking {
+                if let Some(id) = radar.tracking {
+                    radar.radar.stop_tracking(id);
+                }
+
+                if let Some(id) = radar.track {
+                    if let Err(error) = radar.radar.start_tracking(id) {
+                        error::report(error);
+                    }
+                }
+
+                radar.tracking = radar.track;
+            }
+
+            radar.radar.scan(&position());
+
+            for event in radar.events.update(&radar.radar) {
+                world.events.send(WorldEvent::Radar(entity, event));
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Raises an event for each radio message received.
+///
+pub struct ReceiveRadio;
+
+impl System for ReceiveRadio {
+    fn run(&mut self, world: &mut World) {
+        for (entity, radio) in world.radio.iter_mut() {
+            while let Some((_, message)) = radio.transport.receive() {
+                world.events.send(WorldEvent::Message(entity, message));
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Picks the closest contact of the class being hunted and sets the radar, weapons and steering
+/// to go after it.
+///
+pub struct Hunt;
+
+impl System for Hunt {
+    fn run(&mut self, world: &mut World) {
+        for (entity, behaviour) in world.behaviour.iter_mut() {
+            let Some(radar) = world.radar.get_mut(entity) else {
+                continue;
+            };
+
+            let lost = |id| {
+                let event = WorldEvent::Radar(entity, Event::ContactLost(id));
+                return world.events.iter().any(|e| *e == event);
+            };
+
+            if behaviour.target.is_some_and(lost) {
+                behaviour.target = None;
+            }
+
+            if behaviour.target.is_none() {
+                let here = position();
+                behaviour.target = radar
+                    .radar
+                    .contacts
+                    .iter()
+                    .filter(|(_, c)| c.class() == behaviour.prey)
+                    .min_by(|(_, a), (_, b)| a.distance_to(&here).total_cmp(&b.distance_to(&here)))
+                    .map(|(id, _)| *id);
+            }
+
+            radar.track = behaviour.target;
+
+            if let Some(weapons) = world.weapons.get_mut(entity) {
+                weapons.target = behaviour.target;
+            }
+
+            if let Some(navigation) = world.navigation.get_mut(entity) {
+                let contact = behaviour
+                    .target
+                    .and_then(|id| Some((id, radar.radar.contacts.get(id)?)));
+
+                navigation.steering = match contact {
+                    Some((id, Contact::Tracked(_))) => Steering::Engage(id),
+                    Some((_, contact)) => Steering::Approach(contact.position()),
+                    None => Steering::Approach(vec2(0.0, 0.0)),
+                };
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Flies the steering set by other systems.
+///
+pub struct Navigate;
+
+impl System for Navigate {
+    fn run(&mut self, world: &mut World) {
+        for (entity, navigation) in world.navigation.iter_mut() {
+            navigation.aim = None;
+
+            let target = match navigation.steering {
+                Steering::Approach(position) => Err(position),
+                Steering::Engage(id) => {
+                    let contact = world
+                        .radar
+                        .get(entity)
+                        .and_then(|r| r.radar.contacts.get(id));
+                    let speed = world.weapons.get(entity).and_then(|w| w.bullet_speed());
+
+                    match (contact, speed) {
+                        (Some(Contact::Tracked(target)), Some(speed)) => {
+                            FiringSolution::new(&*navigation, speed, target)
+                                .map_err(|_| target.position())
+                        }
+                        (Some(contact), _) => Err(contact.position()),
+                        (None, _) => Err(navigation.position()),
+                    }
+                }
+            };
+
+            match target {
+                Ok(solution) => {
+                    navigation.turn_to_track(&solution);
+                    navigation.accelerate_towards(&solution);
+                    navigation.aim = Some(solution.position());
+
+                    draw::aim_reticle(&solution);
+                }
+
+                Err(position) => {
+                    navigation.turn_to_face(&position);
+                    navigation.accelerate_towards(&position);
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Fires the guns and launches missiles once they're pointed at the target. Launches are
+/// recorded with the radio so the target can be handed over.
+///
+pub struct FireWeapons;
+
+impl System for FireWeapons {
+    fn run(&mut self, world: &mut World) {
+        for (entity, weapons) in world.weapons.iter() {
+            let Some(navigation) = world.navigation.get(entity) else {
+                continue;
+            };
+
+            let Some(aim) = navigation.aim else {
+                continue;
+            };
+
+            let bearing = navigation.relative_bearing_to(&aim).abs();
+            if bearing < GUN_BEARING {
+                for gun in weapons.loadout.iter().filter(|w| w.is_gun()) {
+                    gun.fire();
+                }
+            }
+
+            let Some(radio) = world.radio.get_mut(entity) else {
+                continue;
+            };
+
+            if bearing < MISSILE_BEARING {
+                let launchers = weapons.loadout.iter().copied().filter(Weapon::is_launcher);
+                if Weapon::launch(launchers, &mut radio.channels) == Outcome::Fired {
+                    world.events.send(WorldEvent::Launched(entity));
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Tunes the radio, sends the target to missiles in flight and transmits anything queued.
+///
+pub struct TransmitRadio;
+
+impl System for TransmitRadio {
+    fn run(&mut self, world: &mut World) {
+        for (entity, radio) in world.radio.iter_mut() {
+            let tuning = radio.channels.tune(current_tick());
+
+            let target = world.weapons.get(entity).and_then(|w| w.target);
+            let contact = target.and_then(|id| world.radar.get(entity)?.radar.contacts.get(id));
+
+            let channel = match tuning {
+                Tuning::Launch(channel) | Tuning::Salvo(channel) => Some(channel),
+                Tuning::Fleet { .. } => None,
+            };
+
+            if let (Some(Contact::Tracked(target)), Some(channel)) = (contact, channel) {
+                radio.transport.send(
+                    BROADCAST,
+                    RadioMessage::MissileDatalink(MissileDatalink {
+                        channel,
+                        class: target.class(),
+                        position: target.position(),
+                        velocity: target.velocity(),
+                        time: target.time(),
+                    }),
+                );
+            }
+
+            let next = radio.channels.plan(current_tick());
+            radio.transport.flush(tuning, next);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Adjusts the radar for our own movement ready for the next scan.
+///
+pub struct AdjustRadar;
+
+impl System for AdjustRadar {
+    fn run(&mut self, world: &mut World) {
+        for (entity, radar) in world.radar.iter_mut() {
+            if let Some(navigation) = world.navigation.get(entity) {
+                radar.radar.adjust(&KinematicModel::from(navigation));
+            }
+
+            radar.radar.draw_contacts();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,242 @@
+use super::{
+    behaviour::Event,
+    components::{Behaviour, Navigation, Radar, Radio, Weapons},
+    radio::RadioMessage,
+};
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Entity(usize);
+
+/// Description
+/// -----------
+/// Storage for one type of component, indexed by entity.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Components<T> {
+    slots: Vec<Option<T>>,
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Something a system wants later systems to know about.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub enum WorldEvent {
+    Radar(Entity, Event<'static>),
+    Message(Entity, RadioMessage),
+
+    /// A missile has left the launcher.
+    Launched(Entity),
+}
+
+/// Description
+/// -----------
+/// Events raised during the current tick. Cleared at the start of each tick so every system sees
+/// the events raised by the systems before it.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Events {
+    queue: Vec<WorldEvent>,
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Every entity and it's components, stored as one array per component type.
+///
+#[derive(Default)]
+pub struct World {
+    entities: usize,
+
+    pub radar: Components<Radar>,
+    pub radio: Components<Radio>,
+    pub weapons: Components<Weapons>,
+    pub navigation: Components<Navigation>,
+    pub behaviour: Components<Behaviour>,
+
+    pub events: Events,
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Runs over the entities in a world that have the components it needs.
+///
+pub trait System {
+    fn run(&mut self, world: &mut World);
+}
+
+/// Description
+/// -----------
+/// Systems in the order they're run each tick.
+///
+pub struct Schedule {
+    systems: Vec<Box<dyn System>>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl<T> Default for Components<T> {
+    fn default() -> Self {
+        return Self { slots: Vec::new() };
+    }
+}
+
+impl<T> Components<T> {
+    pub fn insert(&mut self, entity: Entity, component: T) {
+        if self.slots.len() <= entity.0 {
+            self.slots.resize_with(entity.0 + 1, || None);
+        }
+
+        self.slots[entity.0] = Some(component);
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        return self.slots.get(entity.0)?.as_ref();
+    }
+
+    pub fn get_mut(&mut self, entity: Entity) -> Option<&mut T> {
+        return self.slots.get_mut(entity.0)?.as_mut();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
+        return self
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((Entity(index), slot.as_ref()?)));
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, &mut T)> {
+        return self
+            .slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((Entity(index), slot.as_mut()?)));
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Events {
+    pub fn send(&mut self, event: WorldEvent) {
+        self.queue.push(event);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &WorldEvent> {
+        return self.queue.iter();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl World {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn spawn(&mut self) -> Entity {
+        self.entities += 1;
+        return Entity(self.entities - 1);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Schedule {
+    pub fn new(systems: Vec<Box<dyn System>>) -> Self {
+        return Self { systems };
+    }
+
+    /// Description
+    /// -----------
+    /// Run every system once, in order.
+    ///
+    pub fn run(&mut self, world: &mut World) {
+        world.events.queue.clear();
+
+        for system in self.systems.iter_mut() {
+            system.run(world);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        let mut world = World::new();
+        let first = world.spawn();
+        let second = world.spawn();
+
+        let mut components = Components::default();
+        components.insert(second, 2);
+        assert_eq!(components.get(first), None);
+        assert_eq!(components.get(second), Some(&2));
+
+        components.insert(first, 1);
+        *components.get_mut(first).unwrap() += 10;
+        assert_eq!(
+            components.iter().collect::<Vec<_>>(),
+            [(first, &11), (second, &2)]
+        );
+    }
+
+    /// Raises an event, or counts how many have been raised so far this tick.
+    struct Probe {
+        raise: bool,
+        seen: Rc<Cell<usize>>,
+    }
+
+    impl System for Probe {
+        fn run(&mut self, world: &mut World) {
+            if self.raise {
+                world.events.send(WorldEvent::Launched(Entity(0)));
+            } else {
+                self.seen.set(world.events.iter().count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_schedule() {
+        let mut world = World::new();
+        let before = Rc::new(Cell::new(0));
+        let after = Rc::new(Cell::new(0));
+
+        // Systems see events raised earlier in the same tick, but not those from the last tick.
+        let mut schedule = Schedule::new(vec![
+            Box::new(Probe {
+                raise: false,
+                seen: before.clone(),
+            }),
+            Box::new(Probe {
+                raise: true,
+                seen: Rc::new(Cell::new(0)),
+            }),
+            Box::new(Probe {
+                raise: false,
+                seen: after.clone(),
+            }),
+        ]);
+
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(before.get(), 0);
+        assert_eq!(after.get(), 1);
+    }
+}
+
+////////////////////////////////////////////////////////////////
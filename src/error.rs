@@ -0,0 +1,160 @@
+use std::{cell::RefCell, collections::VecDeque};
+
+use oort_api::prelude::*;
+
+use super::{math, radar, radio};
+
+////////////////////////////////////////////////////////////////
+
+/// Number of errors kept in the log.
+const LOG_LENGTH: usize = 5;
+
+thread_local! {
+    /// Errors reported since they were last collected into a log.
+    static UNHANDLED: RefCell<Vec<Error>> = const { RefCell::new(Vec::new()) };
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Anything that can go wrong in the crate.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub enum Error {
+    /// The radar couldn't carry out a request.
+    Radar(radar::Error),
+
+    /// A received radio message couldn't be authenticated or decoded.
+    Radio(radio::Error),
+
+    /// An equation had no usable root, e.g. a target that can't be caught.
+    NoSolution,
+
+    /// There's no firing solution on a target, and why.
+    Miss(math::Miss),
+
+    /// There are no stats for a class of ship.
+    NoStats(Class),
+
+    /// The sandbox mode isn't one we know.
+    UnknownSandboxMode(&'static str),
+}
+
+/// Description
+/// -----------
+/// The last few errors reported and the tick they were collected on.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ErrorLog {
+    entries: VecDeque<(u32, Error)>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl From<radar::Error> for Error {
+    fn from(error: radar::Error) -> Self {
+        return Self::Radar(error);
+    }
+}
+
+impl From<math::Miss> for Error {
+    fn from(miss: math::Miss) -> Self {
+        return Self::Miss(miss);
+    }
+}
+
+impl From<radio::Error> for Error {
+    fn from(error: radio::Error) -> Self {
+        return Self::Radio(error);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Report an error that couldn't be handled where it happened. It'll be picked up by the ship's
+/// error log at the end of the tick.
+///
+pub fn report(error: impl Into<Error>) {
+    UNHANDLED.with_borrow_mut(|errors| errors.push(error.into()));
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ErrorLog {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Description
+    /// -----------
+    /// Add an error to the log, dropping the oldest if it's full.
+    ///
+    pub fn push(&mut self, tick: u32, error: Error) {
+        if self.entries.len() == LOG_LENGTH {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back((tick, error));
+    }
+
+    /// Description
+    /// -----------
+    /// Move every error reported since the last collection into the log.
+    ///
+    pub fn collect(&mut self, tick: u32) {
+        for error in UNHANDLED.take() {
+            self.push(tick, error);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(u32, Error)> {
+        return self.entries.iter();
+    }
+
+    pub fn draw(&self) {
+        for (tick, error) in self.iter() {
+            debug!("ERROR {tick} - {error:?}");
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log() {
+        let mut log = ErrorLog::new();
+        for tick in 0..(LOG_LENGTH as u32 + 2) {
+            log.push(tick, Error::NoSolution);
+        }
+
+        // Only the most recent errors are kept, oldest first.
+        let ticks: Vec<u32> = log.iter().map(|(tick, _)| *tick).collect();
+        assert_eq!(ticks, [2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_report() {
+        let mut log = ErrorLog::new();
+        report(radio::Error::Truncated);
+        report(Error::NoStats(Class::Unknown));
+
+        log.collect(10);
+        log.collect(11);
+        assert_eq!(
+            log.iter().cloned().collect::<Vec<_>>(),
+            [
+                (10, Error::Radio(radio::Error::Truncated)),
+                (10, Error::NoStats(Class::Unknown))
+            ]
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////
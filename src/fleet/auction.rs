@@ -0,0 +1,427 @@
+use std::collections::BTreeMap;
+
+use oort_api::prelude::*;
+
+use super::{
+    control::Rotation,
+    identify::{fix, identify},
+    math::kinematics::Velocity,
+    radar::{board::ContactBoard, CompositeRadar},
+    radio::Bid,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Improvement in cost needed to take a target away from the ship that already holds it. Stops
+/// ships with similar costs swapping targets back and forth, and covers the precision lost when
+/// bids are encoded.
+const MIN_INCREMENT: f64 = 0.25; // s
+
+/// Time after which a claim that hasn't been repeated is assumed to belong to a ship that's been
+/// destroyed or has lost the target.
+const CLAIM_TIMEOUT: f64 = 2.0; // s
+
+/// Time between repeats of an unchanged bid.
+const REBID_INTERVAL: f64 = 0.5; // s
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Estimate how long it would take a ship to bring it's guns to bear on a target. Used as the cost
+/// when bidding for targets.
+///
+/// Parmaters
+/// ---------
+/// * `shooter` - Ship doing the shooting.
+/// * `target` - Target to be shot.
+/// * `projectile_speed` - Speed of the shooter's projectiles.
+///
+pub fn intercept_cost<T, U>(shooter: &T, target: &U, projectile_speed: f64) -> f64
+where
+    T: Rotation,
+    U: Velocity,
+{
+    let offset = target.position_relative_to(shooter);
+    let distance = offset.length();
+    let closing_speed = -target.velocity_relative_to(shooter).dot(offset) / distance.max(1.0);
+
+    // Time for projectiles to reach the target. Don't let targets running away faster than our
+    // projectiles become free.
+    let flight = distance / f64::max(projectile_speed + closing_speed, projectile_speed / 10.0);
+
+    let turn = shooter.time_to_align(target);
+
+    return flight + turn;
+}
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Claim {
+    bidder: u16,
+    cost: f64,
+    time: f64,
+}
+
+impl Claim {
+    /// Description
+    /// -----------
+    /// Settle two claims made on the same target. Every ship settles conflicting claims in the
+    /// same way so they all agree on the winner, even if they hear the bids in a different order.
+    ///
+    fn beats(&self, other: &Claim) -> bool {
+        return self.cost < other.cost || (self.cost == other.cost && self.bidder < other.bidder);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Shares targets out between the team so that each ship engages a different one.
+///
+/// Each ship claims the target it can engage at the lowest cost and broadcasts a bid for it. A
+/// ship can only take a target claimed by another by beating it's cost, so claims settle after a
+/// round or two of bids. The auction is re-run every tick so ships move on as soon as a target is
+/// destroyed or a new one turns up.
+///
+/// Only one target is claimed per ship. Once every target has been claimed, ships left without
+/// one double up on whichever target is cheapest for them.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Auction {
+    id: u16,
+
+    /// Claims on our contacts, keyed by contact ID.
+    claims: BTreeMap<usize, Claim>,
+    assigned: Option<usize>,
+
+    changed: bool,
+    last_bid: Option<f64>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Auction {
+    pub fn new(id: u16) -> Self {
+        return Self {
+            id,
+
+            claims: BTreeMap::new(),
+            assigned: None,
+
+            changed: false,
+            last_bid: None,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Auction {
+    /// Description
+    /// -----------
+    /// Return the contact ID of the target we've been assigned.
+    ///
+    pub fn assigned(&self) -> Option<usize> {
+        return self.assigned;
+    }
+
+    /// Description
+    /// -----------
+    /// Record a bid received from another ship.
+    ///
+    pub fn receive(&mut self, radar: &CompositeRadar, bid: &Bid) {
+        if bid.bidder == self.id {
+            return;
+        }
+
+        // Ships only hold one claim at a time so the bidder has moved on from any others.
+        self.claims.retain(|_, claim| claim.bidder != bid.bidder);
+
+        let Some(id) = identify(radar, bid.class, bid.position, bid.velocity, bid.time) else {
+            return;
+        };
+
+        let claim = Claim {
+            bidder: bid.bidder,
+            cost: bid.cost,
+            time: current_time(),
+        };
+
+        if self.claims.get(&id).is_none_or(|other| claim.beats(other)) {
+            self.claims.insert(id, claim);
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Re-run the auction using our own costs for each target. Should be called once per tick
+    /// after any bids have been received.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `radar` - Radar holding the targets.
+    /// * `costs` - Contact IDs of the targets we're willing to engage, with our cost for each.
+    ///
+    pub fn update<I>(&mut self, radar: &CompositeRadar, costs: I)
+    where
+        I: IntoIterator<Item = (usize, f64)>,
+    {
+        let now = current_time();
+
+        // Forget claims on targets we've lost and claims that are no longer being repeated.
+        let id = self.id;
+        self.claims.retain(|target, claim| {
+            radar.contacts.get(*target).is_some()
+                && (claim.bidder == id || now - claim.time <= CLAIM_TIMEOUT)
+        });
+
+        let costs: Vec<(usize, f64)> = costs
+            .into_iter()
+            .filter(|(target, _)| radar.contacts.get(*target).is_some())
+            .collect();
+
+        let held_id = self
+            .claims
+            .iter()
+            .find(|(_, claim)| claim.bidder == self.id)
+            .map(|(target, _)| *target);
+
+        let best = costs
+            .iter()
+            .filter(|(target, cost)| match self.claims.get(target) {
+                Some(claim) => claim.bidder == self.id || *cost < claim.cost - MIN_INCREMENT,
+                None => true,
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        // Don't give up a target for one that's only a little cheaper.
+        let held = held_id.and_then(|target| costs.iter().find(|(id, _)| *id == target));
+        let choice = match (held, best) {
+            (Some(held), Some(best)) if held.1 <= best.1 + MIN_INCREMENT => Some(held),
+            (_, best) => best,
+        };
+
+        let choice_id = choice.map(|(target, _)| *target);
+        if let Some(held) = held_id.filter(|held| Some(*held) != choice_id) {
+            self.claims.remove(&held);
+        }
+
+        if let Some((target, cost)) = choice {
+            self.claims.insert(
+                *target,
+                Claim {
+                    bidder: self.id,
+                    cost: *cost,
+                    time: now,
+                },
+            );
+        }
+
+        // With nothing left to claim, help out with whichever target is cheapest.
+        let assigned = choice
+            .or_else(|| costs.iter().min_by(|(_, a), (_, b)| a.total_cmp(b)))
+            .map(|(target, _)| *target);
+
+        self.changed |= held_id != choice_id;
+        self.assigned = assigned;
+    }
+
+    /// Description
+    /// -----------
+    /// Return our bid if it should be broadcast. Bids are sent as soon as we claim a different
+    /// target and repeated periodically after that so the rest of the team knows we're still
+    /// holding it.
+    ///
+    pub fn take_bid(&mut self, radar: &CompositeRadar) -> Option<Bid> {
+        let now = current_time();
+        if !self.changed
+            && self
+                .last_bid
+                .is_some_and(|time| now - time < REBID_INTERVAL)
+        {
+            return None;
+        }
+
+        let (target, claim) = self
+            .claims
+            .iter()
+            .find(|(_, claim)| claim.bidder == self.id)?;
+        let contact = radar.contacts.get(*target)?;
+        let (position, time) = fix(contact);
+
+        self.changed = false;
+        self.last_bid = Some(now);
+
+        return Some(Bid {
+            bidder: self.id,
+            class: contact.class(),
+            position,
+            velocity: contact.velocity(),
+            cost: claim.cost,
+            time,
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::math::kinematics::Position;
+    use crate::radar::{board::UniqueContactBoard, contacts::RemoteContact};
+
+    use super::*;
+
+    fn radar(targets: &[Vec2]) -> CompositeRadar {
+        let mut radar = CompositeRadar::new(UniqueContactBoard::new());
+        for target in targets {
+            radar.fuse(RemoteContact::new(
+                9,
+                0.0,
+                Class::Fighter,
+                *target,
+                vec2(0.0, 0.0),
+                10.0,
+            ));
+        }
+
+        return radar;
+    }
+
+    fn id_at(radar: &CompositeRadar, position: Vec2) -> Option<usize> {
+        return radar
+            .contacts
+            .iter()
+            .find(|(_, contact)| contact.position().distance(position) < 1.0)
+            .map(|(id, _)| *id);
+    }
+
+    /// Costs for a ship at the given position, one second per kilometer.
+    fn costs(radar: &CompositeRadar, position: Vec2) -> Vec<(usize, f64)> {
+        return radar
+            .contacts
+            .iter()
+            .map(|(id, contact)| (*id, contact.position().distance(position) / 1000.0))
+            .collect();
+    }
+
+    fn bid(bidder: u16, position: Vec2, cost: f64) -> Bid {
+        return Bid {
+            bidder,
+            class: Class::Fighter,
+            position,
+            velocity: vec2(0.0, 0.0),
+            cost,
+            time: 0.0,
+        };
+    }
+
+    #[test]
+    fn test_claims_cheapest_target() {
+        let near = vec2(1000.0, 0.0);
+        let far = vec2(5000.0, 0.0);
+        let radar = radar(&[far, near]);
+
+        let mut auction = Auction::new(1);
+        assert_eq!(auction.assigned(), None);
+
+        auction.update(&radar, costs(&radar, vec2(0.0, 0.0)));
+        assert_eq!(auction.assigned(), id_at(&radar, near));
+
+        // The claim is broadcast straight away then held back until it's due to be repeated.
+        let sent = auction.take_bid(&radar).unwrap();
+        assert_eq!(sent.bidder, 1);
+        assert_eq!(sent.position, near);
+        assert_eq!(sent.cost, 1.0);
+        assert_eq!(auction.take_bid(&radar), None);
+
+        // Targets we don't want are never claimed.
+        let mut auction = Auction::new(1);
+        auction.update(&radar, [(id_at(&radar, far).unwrap(), 4.0)]);
+        assert_eq!(auction.assigned(), id_at(&radar, far));
+    }
+
+    #[test]
+    fn test_outbid() {
+        let near = vec2(1000.0, 0.0);
+        let far = vec2(3000.0, 0.0);
+        let radar = radar(&[near, far]);
+
+        let mut auction = Auction::new(1);
+        auction.update(&radar, costs(&radar, vec2(0.0, 0.0)));
+        auction.take_bid(&radar);
+        assert_eq!(auction.assigned(), id_at(&radar, near));
+
+        // A cheaper bid takes the target and we move on to the next one.
+        auction.receive(&radar, &bid(2, vec2(1010.0, 0.0), 0.5));
+        auction.update(&radar, costs(&radar, vec2(0.0, 0.0)));
+        assert_eq!(auction.assigned(), id_at(&radar, far));
+        assert_eq!(auction.take_bid(&radar).unwrap().position, far);
+
+        // Only a little cheaper isn't enough to take it back.
+        let mut auction = Auction::new(1);
+        auction.receive(&radar, &bid(2, near, 1.1));
+        auction.update(&radar, costs(&radar, vec2(0.0, 0.0)));
+        assert_eq!(auction.assigned(), id_at(&radar, far));
+
+        // Bids for targets we can't see are ignored.
+        let mut auction = Auction::new(1);
+        auction.receive(&radar, &bid(2, vec2(-5000.0, 0.0), 0.1));
+        auction.update(&radar, costs(&radar, vec2(0.0, 0.0)));
+        assert_eq!(auction.assigned(), id_at(&radar, near));
+    }
+
+    #[test]
+    fn test_double_up() {
+        let target = vec2(1000.0, 0.0);
+        let radar = radar(&[target]);
+
+        let mut auction = Auction::new(1);
+        auction.receive(&radar, &bid(2, target, 0.1));
+        auction.update(&radar, costs(&radar, vec2(0.0, 0.0)));
+
+        assert_eq!(auction.assigned(), id_at(&radar, target));
+        assert_eq!(auction.take_bid(&radar), None);
+    }
+
+    /// Description
+    /// -----------
+    /// Test that ships which start out claiming the same target settle on different ones once
+    /// they've heard each other's bids.
+    ///
+    #[test]
+    fn test_converges() {
+        let targets = [vec2(0.0, 1000.0), vec2(0.0, -1000.0), vec2(2000.0, 0.0)];
+        let ships = [vec2(0.0, 200.0), vec2(0.0, 100.0), vec2(300.0, 0.0)];
+        let radar = radar(&targets);
+
+        let mut auctions: Vec<Auction> = (0..3).map(|id| Auction::new(id as u16)).collect();
+
+        for _ in 0..3 {
+            let mut bids = Vec::new();
+            for (auction, ship) in auctions.iter_mut().zip(ships) {
+                auction.update(&radar, costs(&radar, ship));
+                bids.extend(auction.take_bid(&radar));
+            }
+
+            for auction in auctions.iter_mut() {
+                for bid in bids.iter() {
+                    auction.receive(&radar, bid);
+                }
+            }
+        }
+
+        let mut assigned: Vec<_> = auctions.iter().map(|a| a.assigned().unwrap()).collect();
+        assigned.sort();
+        assigned.dedup();
+        assert_eq!(assigned.len(), 3);
+
+        // The ship nearest the first target keeps it.
+        assert_eq!(auctions[0].assigned(), id_at(&radar, targets[0]));
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,384 @@
+use oort_api::prelude::*;
+
+use super::{
+    election::Election,
+    identify::{fix, identify},
+    math::kinematics::{Position, Velocity},
+    radar::{board::ContactBoard, CompositeRadar},
+    radio::{FleetOrder, Formation, Heartbeat, Order, TargetAssignment},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Time between repeats of an unchanged order.
+const ORDER_INTERVAL: f64 = 1.0; // s
+
+/// Time a target assignment is followed for if it's not replaced.
+const ASSIGNMENT_TIMEOUT: f64 = 10.0; // s
+
+/// How much stronger the enemy has to be before the fleet retreats.
+const RETREAT_RATIO: f64 = 2.0;
+
+/// Distance the fleet falls back from the enemy when retreating.
+const RETREAT_DISTANCE: f64 = 10_000.0; // m
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return a rough measure of how much a ship of the given class contributes to a fight. Ships
+/// that can't fight back contribute nothing.
+///
+fn strength(class: Class) -> f64 {
+    return match class {
+        Class::Fighter => 1.0,
+        Class::Frigate => 4.0,
+        Class::Cruiser => 8.0,
+        _ => 0.0,
+    };
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Carries orders down the fleet's chain of command.
+///
+/// Whichever ship the election makes commander decides what the fleet does and issues orders to
+/// the rest. Everyone else follows the latest orders from the commander, ignoring any still
+/// arriving from a commander that's been replaced.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Command {
+    id: u16,
+
+    /// Latest orders received.
+    order: Option<FleetOrder>,
+    assignment: Option<(TargetAssignment, f64)>,
+
+    /// Latest orders issued while commander.
+    issued: Option<(Order, f64)>,
+    issued_assignment: Option<(u16, usize)>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Command {
+    pub fn new(id: u16) -> Self {
+        return Self {
+            id,
+
+            order: None,
+            assignment: None,
+
+            issued: None,
+            issued_assignment: None,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Command {
+    pub fn receive_order(&mut self, order: &FleetOrder) {
+        self.order = Some(order.clone());
+    }
+
+    pub fn receive_assignment(&mut self, assignment: &TargetAssignment) {
+        if assignment.assignee == self.id {
+            self.assignment = Some((assignment.clone(), current_time()));
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Return the order we're currently following.
+    ///
+    pub fn order(&self, election: &Election) -> Order {
+        if election.is_leader() {
+            return self.issued.map_or(Order::Engage, |(order, _)| order);
+        }
+
+        return self
+            .order
+            .as_ref()
+            .filter(|order| order.commander == election.leader())
+            .map_or(Order::Engage, |order| order.order);
+    }
+
+    /// Description
+    /// -----------
+    /// Return the contact ID of the target the commander has ordered us to engage, if any.
+    ///
+    pub fn assigned_target(&self, election: &Election, radar: &CompositeRadar) -> Option<usize> {
+        let (assignment, time) = self.assignment.as_ref()?;
+        if assignment.assigner != election.leader() || current_time() - time > ASSIGNMENT_TIMEOUT {
+            return None;
+        }
+
+        return identify(
+            radar,
+            assignment.class,
+            assignment.position,
+            assignment.velocity,
+            *time,
+        );
+    }
+
+    /// Description
+    /// -----------
+    /// Decide what the fleet should be doing, if we're it's commander.
+    ///
+    /// Returns
+    /// -------
+    /// The order to broadcast, if it's changed or is due to be repeated.
+    ///
+    pub fn take_order(
+        &mut self,
+        election: &Election,
+        radar: &CompositeRadar,
+        commander: &Heartbeat,
+    ) -> Option<FleetOrder> {
+        if !election.is_leader() {
+            self.issued = None;
+            return None;
+        }
+
+        let now = current_time();
+        let order = Self::decide(election, radar, commander);
+
+        let repeat = self
+            .issued
+            .is_some_and(|(issued, time)| issued == order && now - time < ORDER_INTERVAL);
+        if repeat {
+            return None;
+        }
+
+        self.issued = Some((order, now));
+        return Some(FleetOrder {
+            commander: self.id,
+            order,
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// While engaging, have the ship nearest the most dangerous enemy deal with it, if we're the
+    /// fleet's commander.
+    ///
+    /// Returns
+    /// -------
+    /// The assignment to send, if it's changed.
+    ///
+    pub fn take_assignment(
+        &mut self,
+        election: &Election,
+        radar: &CompositeRadar,
+    ) -> Option<TargetAssignment> {
+        if !election.is_leader() || self.order(election) != Order::Engage {
+            self.issued_assignment = None;
+            return None;
+        }
+
+        // Only capital ships are worth overriding the target auction for.
+        let (target_id, target) = radar
+            .contacts
+            .iter()
+            .filter(|(_, contact)| strength(contact.class()) > strength(Class::Fighter))
+            .max_by(|(_, a), (_, b)| strength(a.class()).total_cmp(&strength(b.class())))?;
+
+        let assignee = election
+            .members()
+            .min_by(|a, b| {
+                let a = a.position.distance(target.position());
+                let b = b.position.distance(target.position());
+                a.total_cmp(&b)
+            })?
+            .sender;
+
+        if self.issued_assignment == Some((assignee, *target_id)) {
+            return None;
+        }
+
+        self.issued_assignment = Some((assignee, *target_id));
+
+        // Assignments aren't timestamped so send where the target should be now.
+        let (position, time) = fix(target);
+        return Some(TargetAssignment {
+            assigner: self.id,
+            assignee,
+            class: target.class(),
+            position: position + (target.velocity() * (current_time() - time)),
+            velocity: target.velocity(),
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Choose the fleet's order. Form up while there's nothing to fight, engage if it's a fair
+    /// fight and fall back if we're outmatched.
+    ///
+    fn decide(election: &Election, radar: &CompositeRadar, commander: &Heartbeat) -> Order {
+        let enemies: Vec<Vec2> = radar
+            .contacts
+            .iter()
+            .filter(|(_, contact)| strength(contact.class()) > 0.0)
+            .map(|(_, contact)| contact.position())
+            .collect();
+
+        if enemies.is_empty() {
+            return Order::FormUp(Formation::Wedge);
+        }
+
+        let enemy_strength: f64 = radar
+            .contacts
+            .iter()
+            .map(|(_, contact)| strength(contact.class()))
+            .sum();
+
+        let fleet_strength: f64 = election
+            .members()
+            .map(|member| strength(member.class))
+            .sum::<f64>()
+            + strength(commander.class);
+
+        if enemy_strength <= fleet_strength * RETREAT_RATIO {
+            return Order::Engage;
+        }
+
+        // Fall back directly away from the enemy.
+        let centre = enemies.iter().fold(vec2(0.0, 0.0), |sum, p| sum + *p) / enemies.len() as f64;
+        let away = commander.position - centre;
+        let away = if away.length() > 0.0 {
+            away.normalize()
+        } else {
+            vec2(1.0, 0.0)
+        };
+
+        return Order::Retreat(commander.position + (away * RETREAT_DISTANCE));
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::radar::{board::UniqueContactBoard, contacts::RemoteContact};
+    use crate::radio::Role;
+
+    use super::*;
+
+    fn radar(targets: &[(Class, Vec2)]) -> CompositeRadar {
+        let mut radar = CompositeRadar::new(UniqueContactBoard::new());
+        for (class, position) in targets {
+            radar.fuse(RemoteContact::new(
+                9,
+                0.0,
+                *class,
+                *position,
+                vec2(0.0, 0.0),
+                10.0,
+            ));
+        }
+
+        return radar;
+    }
+
+    fn heartbeat(sender: u16, position: Vec2) -> Heartbeat {
+        return Heartbeat {
+            sender,
+            class: Class::Fighter,
+            role: Role::Fighter,
+            position,
+            velocity: vec2(0.0, 0.0),
+            heading: 0.0,
+        };
+    }
+
+    #[test]
+    fn test_decide() {
+        let commander = heartbeat(1, vec2(0.0, 0.0));
+        let mut election = Election::new(1);
+        election.receive(&heartbeat(2, vec2(0.0, 100.0)));
+
+        let mut command = Command::new(1);
+        let order = command.take_order(&election, &radar(&[]), &commander);
+        assert_eq!(order.unwrap().order, Order::FormUp(Formation::Wedge));
+
+        // Unchanged orders aren't repeated straight away.
+        assert_eq!(command.take_order(&election, &radar(&[]), &commander), None);
+
+        let fair = radar(&[(Class::Fighter, vec2(5000.0, 0.0))]);
+        let order = command.take_order(&election, &fair, &commander);
+        assert_eq!(order.unwrap().order, Order::Engage);
+        assert_eq!(command.order(&election), Order::Engage);
+
+        let outmatched = radar(&[(Class::Cruiser, vec2(5000.0, 0.0))]);
+        let order = command.take_order(&election, &outmatched, &commander);
+        assert_eq!(
+            order.unwrap().order,
+            Order::Retreat(vec2(-RETREAT_DISTANCE, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_follow_leader() {
+        let mut election = Election::new(5);
+        let mut command = Command::new(5);
+
+        election.receive(&heartbeat(3, vec2(0.0, 0.0)));
+        command.receive_order(&FleetOrder {
+            commander: 3,
+            order: Order::FormUp(Formation::Trail),
+        });
+        assert_eq!(command.order(&election), Order::FormUp(Formation::Trail));
+
+        // Ships that aren't in command don't issue orders.
+        assert_eq!(
+            command.take_order(&election, &radar(&[]), &heartbeat(5, vec2(0.0, 0.0))),
+            None
+        );
+
+        // Orders from a commander that's been replaced are ignored.
+        election.receive(&heartbeat(2, vec2(0.0, 0.0)));
+        assert_eq!(command.order(&election), Order::Engage);
+    }
+
+    #[test]
+    fn test_assignment() {
+        let target = vec2(5000.0, 0.0);
+        let radar = radar(&[
+            (Class::Fighter, vec2(-5000.0, 0.0)),
+            (Class::Frigate, target),
+        ]);
+
+        let mut election = Election::new(1);
+        election.receive(&heartbeat(2, vec2(0.0, 0.0)));
+        election.receive(&heartbeat(3, vec2(4000.0, 0.0)));
+
+        let mut command = Command::new(1);
+        command.take_order(&election, &radar, &heartbeat(1, vec2(0.0, 0.0)));
+
+        // The capital ship goes to the nearest wingman, once.
+        let assignment = command.take_assignment(&election, &radar).unwrap();
+        assert_eq!(assignment.assignee, 3);
+        assert_eq!(assignment.class, Class::Frigate);
+        assert_eq!(command.take_assignment(&election, &radar), None);
+
+        // The wingman finds it on it's own radar.
+        let mut wingman = Command::new(3);
+        let mut wingman_election = Election::new(3);
+        wingman_election.receive(&heartbeat(1, vec2(0.0, 0.0)));
+        wingman.receive_assignment(&assignment);
+
+        let id = wingman.assigned_target(&wingman_election, &radar).unwrap();
+        assert_eq!(radar.contacts.get(id).unwrap().position(), target);
+
+        // Assignments for other ships are ignored.
+        let mut other = Command::new(2);
+        other.receive_assignment(&assignment);
+        assert_eq!(other.assigned_target(&wingman_election, &radar), None);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+
+use oort_api::prelude::*;
+
+use super::radio::Heartbeat;
+
+////////////////////////////////////////////////////////////////
+
+/// Time between heartbeats.
+const HEARTBEAT_INTERVAL: f64 = 1.0; // s
+
+/// Time after which a ship that hasn't sent a heartbeat is assumed to have been destroyed.
+const HEARTBEAT_TIMEOUT: f64 = 3.0; // s
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, PartialEq, Debug)]
+struct Member {
+    heartbeat: Heartbeat,
+    time: f64,
+}
+
+/// Description
+/// -----------
+/// Keeps track of the rest of the fleet and elects one ship to command it.
+///
+/// This is the bully algorithm with the election messages replaced by heartbeats. Every ship
+/// broadcasts a heartbeat and the lowest ID heard from wins. Once the commander's heartbeats stop
+/// every ship drops it and the next lowest ID takes over, without needing to hold another round of
+/// voting.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Election {
+    id: u16,
+    members: BTreeMap<u16, Member>,
+    last_heartbeat: Option<f64>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Election {
+    pub fn new(id: u16) -> Self {
+        return Self {
+            id,
+            members: BTreeMap::new(),
+            last_heartbeat: None,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Election {
+    pub fn id(&self) -> u16 {
+        return self.id;
+    }
+
+    /// Description
+    /// -----------
+    /// Return the ID of the fleet's commander. Can be our own.
+    ///
+    pub fn leader(&self) -> u16 {
+        return self
+            .members
+            .keys()
+            .next()
+            .map_or(self.id, |id| u16::min(*id, self.id));
+    }
+
+    pub fn is_leader(&self) -> bool {
+        return self.leader() == self.id;
+    }
+
+    /// Description
+    /// -----------
+    /// Return the most recent heartbeat from another ship in the fleet.
+    ///
+    pub fn member(&self, id: u16) -> Option<&Heartbeat> {
+        return self.members.get(&id).map(|member| &member.heartbeat);
+    }
+
+    /// Description
+    /// -----------
+    /// Return the time we last heard from another ship in the fleet.
+    ///
+    pub fn last_heard(&self, id: u16) -> Option<f64> {
+        return self.members.get(&id).map(|member| member.time);
+    }
+
+    /// Description
+    /// -----------
+    /// Return the most recent heartbeat from every other ship in the fleet.
+    ///
+    pub fn members(&self) -> impl Iterator<Item = &Heartbeat> {
+        return self.members.values().map(|member| &member.heartbeat);
+    }
+
+    pub fn receive(&mut self, heartbeat: &Heartbeat) {
+        if heartbeat.sender == self.id {
+            return;
+        }
+
+        self.members.insert(
+            heartbeat.sender,
+            Member {
+                heartbeat: heartbeat.clone(),
+                time: current_time(),
+            },
+        );
+    }
+
+    /// Description
+    /// -----------
+    /// Drop ships that have stopped sending heartbeats. Should be called once per tick after any
+    /// heartbeats have been received.
+    ///
+    pub fn update(&mut self) {
+        let now = current_time();
+        self.members
+            .retain(|_, member| now - member.time <= HEARTBEAT_TIMEOUT);
+    }
+
+    /// Description
+    /// -----------
+    /// Return whether our heartbeat is due. If it is, it's assumed to be sent.
+    ///
+    pub fn take_heartbeat(&mut self) -> bool {
+        let now = current_time();
+        if self
+            .last_heartbeat
+            .is_some_and(|time| now - time < HEARTBEAT_INTERVAL)
+        {
+            return false;
+        }
+
+        self.last_heartbeat = Some(now);
+        return true;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::radio::Role;
+
+    use super::*;
+
+    fn heartbeat(sender: u16) -> Heartbeat {
+        return Heartbeat {
+            sender,
+            class: Class::Fighter,
+            role: Role::Fighter,
+            position: vec2(0.0, 0.0),
+            velocity: vec2(0.0, 0.0),
+            heading: 0.0,
+        };
+    }
+
+    #[test]
+    fn test_leader() {
+        let mut election = Election::new(5);
+        assert!(election.is_leader());
+
+        election.receive(&heartbeat(7));
+        assert!(election.is_leader());
+
+        // Lower IDs win.
+        election.receive(&heartbeat(3));
+        election.receive(&heartbeat(5));
+        assert_eq!(election.leader(), 3);
+        assert!(!election.is_leader());
+        assert_eq!(election.member(3), Some(&heartbeat(3)));
+    }
+
+    #[test]
+    fn test_failover() {
+        let mut election = Election::new(5);
+        election.receive(&heartbeat(3));
+        election.members.get_mut(&3).unwrap().time -= HEARTBEAT_TIMEOUT + 0.1;
+
+        election.update();
+        assert!(election.is_leader());
+        assert_eq!(election.member(3), None);
+    }
+
+    #[test]
+    fn test_take_heartbeat() {
+        let mut election = Election::new(1);
+        assert!(election.take_heartbeat());
+        assert!(!election.take_heartbeat());
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,432 @@
+use oort_api::prelude::*;
+
+use super::{
+    election::Election,
+    math::kinematics::{Position, Velocity},
+    radio::{Formation, Order},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Distance between neighbouring slots.
+const SPACING: f64 = 200.0; // m
+
+/// Distance from it's slot within which a ship is on station. Ships that drift twice as far join
+/// up again.
+const STATION_TOLERANCE: f64 = 25.0; // m
+
+/// Distance from it's slot beyond which a ship regroups at full speed rather than joining up.
+/// Ships keep regrouping until they're within half this distance.
+const REGROUP_DISTANCE: f64 = 2_000.0; // m
+
+/// Fastest a ship closes on it's slot, relative to the formation, while regrouping.
+const REGROUP_SPEED: f64 = 1_000.0; // m/s
+
+/// Fastest a ship closes on it's slot, relative to the formation, while joining up.
+const JOIN_SPEED: f64 = 100.0; // m/s
+
+/// Fastest a ship closes on it's slot, relative to the formation, while on station.
+const STATION_SPEED: f64 = 20.0; // m/s
+
+/// Time spent breaking away from the formation before manoeuvring freely.
+const BREAK_TIME: f64 = 2.0; // s
+
+/// Speed to open out from the formation at while breaking away.
+const BREAK_SPEED: f64 = 100.0; // m/s
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return the offset of a slot from the leader in the leader's frame, with x pointing along the
+/// leader's heading and y to it's left. Slot 0 is the leader's own.
+///
+/// Odd slots go on the left and even slots on the right, working outwards from the leader.
+///
+pub fn slot_offset(formation: Formation, slot: usize) -> Vec2 {
+    let rank = slot.div_ceil(2) as f64;
+    let side = if slot % 2 == 1 { 1.0 } else { -1.0 };
+
+    return match formation {
+        Formation::LineAbreast => vec2(0.0, side * rank * SPACING),
+        Formation::Trail => vec2(-(slot as f64) * SPACING, 0.0),
+        Formation::Wedge => vec2(-rank * SPACING, side * rank * SPACING),
+    };
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Where a ship should be, how it should be moving and which way it should be pointing.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Station {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub heading: f64,
+
+    /// Fastest to approach the station at, relative to it's velocity.
+    pub max_closing_speed: f64,
+}
+
+impl Position for Station {
+    fn position(&self) -> Vec2 {
+        return self.position;
+    }
+}
+
+impl Velocity for Station {
+    fn velocity(&self) -> Vec2 {
+        return self.velocity;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Phase {
+    /// Not part of a formation.
+    Free,
+
+    /// Far from the formation and heading back to it at full speed.
+    Regroup,
+
+    /// Closing on our slot.
+    JoinUp,
+
+    /// Holding our slot.
+    OnStation,
+
+    /// Opening out from the formation before manoeuvring freely.
+    BreakAway {
+        direction: Vec2,
+        velocity: Vec2,
+        until: f64,
+    },
+}
+
+/// Description
+/// -----------
+/// Flies a ship in formation on the fleet's commander.
+///
+/// The commander broadcasts it's state in it's heartbeats. Every other ship takes a slot in the
+/// formation, ordered by ID, and works out where that slot is from the commander's last known
+/// state. Ships regroup, join up and hold station as they get closer to their slot, and break away
+/// outwards when the formation is dismissed so they don't collide as they turn to engage.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct FormationKeeper {
+    phase: Phase,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl FormationKeeper {
+    pub fn new() -> Self {
+        return Self { phase: Phase::Free };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl FormationKeeper {
+    pub fn phase(&self) -> Phase {
+        return self.phase;
+    }
+
+    /// Description
+    /// -----------
+    /// Move between phases according to the fleet's order. Should be called once per tick.
+    ///
+    /// Returns
+    /// -------
+    /// The station to hold this tick, or None if we're free to manoeuvre.
+    ///
+    pub fn update<T: Velocity>(
+        &mut self,
+        ship: &T,
+        order: Order,
+        election: &Election,
+    ) -> Option<Station> {
+        let now = current_time();
+        let leader = Self::leader(election, now);
+
+        let formation = match order {
+            Order::FormUp(formation) => Some(formation),
+            Order::Engage | Order::Retreat(_) => None,
+        };
+
+        let slot = formation
+            .zip(leader)
+            .and_then(|(formation, leader)| Self::slot(election, formation, &leader));
+
+        let Some(slot) = slot else {
+            // Only break away when dismissed by the commander. If we've taken over command
+            // there's nobody to break away from.
+            self.phase = match (self.phase, leader) {
+                (Phase::JoinUp | Phase::OnStation, Some(leader)) => {
+                    let away = ship.position() - leader.position;
+                    let direction = if away.length() > 0.0 {
+                        away.normalize()
+                    } else {
+                        vec2(0.0, 1.0).rotate(leader.heading)
+                    };
+
+                    Phase::BreakAway {
+                        direction,
+                        velocity: leader.velocity,
+                        until: now + BREAK_TIME,
+                    }
+                }
+                (Phase::BreakAway { until, .. }, _) if now < until => self.phase,
+                _ => Phase::Free,
+            };
+
+            return match self.phase {
+                Phase::BreakAway {
+                    direction,
+                    velocity,
+                    ..
+                } => Some(Station {
+                    position: ship.position(),
+                    velocity: velocity + (direction * BREAK_SPEED),
+                    heading: direction.angle(),
+                    max_closing_speed: 0.0,
+                }),
+                _ => None,
+            };
+        };
+
+        let distance = slot.distance_to(ship);
+        self.phase = match self.phase {
+            _ if distance > REGROUP_DISTANCE => Phase::Regroup,
+            Phase::Regroup if distance > REGROUP_DISTANCE / 2.0 => Phase::Regroup,
+            Phase::OnStation if distance <= 2.0 * STATION_TOLERANCE => Phase::OnStation,
+            _ if distance <= STATION_TOLERANCE => Phase::OnStation,
+            _ => Phase::JoinUp,
+        };
+
+        return Some(match self.phase {
+            Phase::Regroup => Station {
+                heading: (slot.position - ship.position()).angle(),
+                max_closing_speed: REGROUP_SPEED,
+                ..slot
+            },
+            Phase::JoinUp => Station {
+                max_closing_speed: JOIN_SPEED,
+                ..slot
+            },
+            _ => Station {
+                max_closing_speed: STATION_SPEED,
+                ..slot
+            },
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Return the commander's state, extrapolated from it's last heartbeat. None if we're the
+    /// commander or haven't heard from it.
+    ///
+    fn leader(election: &Election, now: f64) -> Option<Station> {
+        let heartbeat = election.member(election.leader())?;
+        let heard = election.last_heard(heartbeat.sender)?;
+
+        return Some(Station {
+            position: heartbeat.position + (heartbeat.velocity * (now - heard)),
+            velocity: heartbeat.velocity,
+            heading: heartbeat.heading,
+            max_closing_speed: 0.0,
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Return our slot in the formation.
+    ///
+    fn slot(election: &Election, formation: Formation, leader: &Station) -> Option<Station> {
+        let mut wingmen: Vec<u16> = election
+            .members()
+            .map(|member| member.sender)
+            .chain([election.id()])
+            .filter(|id| *id != election.leader())
+            .collect();
+        wingmen.sort();
+
+        let slot = wingmen.iter().position(|id| *id == election.id())? + 1;
+        let offset = slot_offset(formation, slot).rotate(leader.heading);
+
+        return Some(Station {
+            position: leader.position + offset,
+            ..*leader
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::control::{Thrust, Translation};
+    use crate::radio::{Heartbeat, Role};
+
+    use super::*;
+
+    /// Point mass that integrates it's own motion.
+    struct Body {
+        position: Vec2,
+        velocity: Vec2,
+    }
+
+    impl Position for Body {
+        fn position(&self) -> Vec2 {
+            return self.position;
+        }
+    }
+
+    impl Velocity for Body {
+        fn velocity(&self) -> Vec2 {
+            return self.velocity;
+        }
+    }
+
+    impl Translation for Body {
+        fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+            self.position +=
+                (self.velocity * TICK_LENGTH) + (acceleration * TICK_LENGTH.powf(2.0) / 2.0);
+            self.velocity += acceleration * TICK_LENGTH;
+
+            return Thrust {
+                acceleration,
+                residual: vec2(0.0, 0.0),
+            };
+        }
+    }
+
+    fn election(id: u16, others: &[u16]) -> Election {
+        let mut election = Election::new(id);
+        for sender in others {
+            election.receive(&Heartbeat {
+                sender: *sender,
+                class: Class::Fighter,
+                role: Role::Fighter,
+                position: vec2(1000.0, 0.0),
+                velocity: vec2(100.0, 0.0),
+                heading: std::f64::consts::FRAC_PI_2,
+            });
+        }
+
+        return election;
+    }
+
+    #[test]
+    fn test_slot_offset() {
+        assert_eq!(slot_offset(Formation::Wedge, 0), vec2(0.0, 0.0));
+        assert_eq!(slot_offset(Formation::Wedge, 1), vec2(-SPACING, SPACING));
+        assert_eq!(slot_offset(Formation::Wedge, 2), vec2(-SPACING, -SPACING));
+        assert_eq!(
+            slot_offset(Formation::Wedge, 3),
+            vec2(-2.0 * SPACING, 2.0 * SPACING)
+        );
+
+        assert_eq!(slot_offset(Formation::LineAbreast, 2), vec2(0.0, -SPACING));
+        assert_eq!(slot_offset(Formation::Trail, 2), vec2(-2.0 * SPACING, 0.0));
+    }
+
+    #[test]
+    fn test_slot() {
+        // Slots go by ID and are in the leader's frame.
+        let election = election(7, &[2, 5]);
+        let ship = Body {
+            position: vec2(0.0, 0.0),
+            velocity: vec2(0.0, 0.0),
+        };
+
+        let mut keeper = FormationKeeper::new();
+        let order = Order::FormUp(Formation::Trail);
+        let station = keeper.update(&ship, order, &election).unwrap();
+
+        assert!(station.position.distance(vec2(1000.0, -2.0 * SPACING)) < 1e-9);
+        assert_eq!(station.velocity, vec2(100.0, 0.0));
+        assert_eq!(station.heading, std::f64::consts::FRAC_PI_2);
+
+        // The leader flies the formation.
+        let election = Election::new(2);
+        assert_eq!(keeper.update(&ship, order, &election), None);
+    }
+
+    #[test]
+    fn test_phases() {
+        let election = election(3, &[1]);
+        let order = Order::FormUp(Formation::Wedge);
+        let slot = vec2(1000.0, 0.0) + slot_offset(Formation::Wedge, 1).rotate(PI / 2.0);
+
+        let mut keeper = FormationKeeper::new();
+        let mut ship = Body {
+            position: slot + vec2(5000.0, 0.0),
+            velocity: vec2(0.0, 0.0),
+        };
+
+        keeper.update(&ship, order, &election);
+        assert_eq!(keeper.phase(), Phase::Regroup);
+
+        // Keep regrouping until well inside the regroup distance.
+        ship.position = slot + vec2(1500.0, 0.0);
+        keeper.update(&ship, order, &election);
+        assert_eq!(keeper.phase(), Phase::Regroup);
+
+        ship.position = slot + vec2(500.0, 0.0);
+        keeper.update(&ship, order, &election);
+        assert_eq!(keeper.phase(), Phase::JoinUp);
+
+        ship.position = slot + vec2(10.0, 0.0);
+        keeper.update(&ship, order, &election);
+        assert_eq!(keeper.phase(), Phase::OnStation);
+
+        ship.position = slot + vec2(40.0, 0.0);
+        keeper.update(&ship, order, &election);
+        assert_eq!(keeper.phase(), Phase::OnStation);
+
+        // Dismissing the formation opens it out away from the leader.
+        let station = keeper.update(&ship, Order::Engage, &election).unwrap();
+        assert!(matches!(keeper.phase(), Phase::BreakAway { .. }));
+        assert!((station.velocity - vec2(100.0, 0.0)).dot(ship.position - vec2(1000.0, 0.0)) > 0.0);
+    }
+
+    /// Description
+    /// -----------
+    /// Test that a ship joining up settles on a moving slot without overshooting it.
+    ///
+    #[test]
+    fn test_join_up() {
+        let max_acceleration = 30.0;
+        let mut station = Station {
+            position: vec2(0.0, 0.0),
+            velocity: vec2(100.0, 50.0),
+            heading: 0.0,
+            max_closing_speed: JOIN_SPEED,
+        };
+
+        let mut ship = Body {
+            position: vec2(-800.0, 600.0),
+            velocity: station.velocity,
+        };
+
+        let mut closest = f64::MAX;
+        for _ in 0..(60 * 30) {
+            ship.hold_station(&station, max_acceleration, station.max_closing_speed);
+            station.position += station.velocity * TICK_LENGTH;
+
+            // Once it's got close, it should never drift away again.
+            let distance = ship.distance_to(&station);
+            assert!(distance < f64::max(closest, STATION_TOLERANCE) + 1.0);
+            closest = f64::min(closest, distance);
+        }
+
+        assert!(ship.distance_to(&station) < 1.0);
+        assert!((ship.velocity - station.velocity).length() < 1.0);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,62 @@
+use oort_api::prelude::*;
+
+use super::{
+    math::kinematics::Position,
+    radar::{
+        board::ContactBoard,
+        contacts::{Contact, SearchContact, TrackedContact},
+        CompositeRadar,
+    },
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Furthest a target described by another ship can be from one of our contacts and still be taken
+/// to be the same one.
+const MATCH_DISTANCE: f64 = 500.0; // m
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Find our contact for a target described by another ship. Contact IDs are local to each ship so
+/// targets are passed around by class and position instead.
+///
+/// Parmaters
+/// ---------
+/// * `radar` - Radar holding our contacts.
+/// * `class` - Class of the target.
+/// * `position` - Position of the target at `time`.
+/// * `velocity` - Velocity of the target.
+/// * `time` - Time the target was at `position`.
+///
+pub fn identify(
+    radar: &CompositeRadar,
+    class: Class,
+    position: Vec2,
+    velocity: Vec2,
+    time: f64,
+) -> Option<usize> {
+    return radar
+        .contacts
+        .iter()
+        .filter(|(_, contact)| contact.class() == class)
+        .map(|(id, contact)| {
+            let (actual, actual_time) = fix(contact);
+            let expected = position + (velocity * (actual_time - time));
+            (*id, actual.distance(expected))
+        })
+        .filter(|(_, distance)| *distance <= MATCH_DISTANCE)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(id, _)| id);
+}
+
+/// Description
+/// -----------
+/// Return a contact's position and the time it was at that position.
+///
+pub fn fix(contact: &Contact<SearchContact, TrackedContact>) -> (Vec2, f64) {
+    return (contact.position(), contact.time());
+}
+
+////////////////////////////////////////////////////////////////
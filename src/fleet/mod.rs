@@ -0,0 +1,21 @@
+//! Description
+//! -----------
+//! Coordination between the ships of a fleet.
+//!
+
+mod auction;
+mod command;
+mod election;
+mod formation;
+mod identify;
+
+// Imports
+use super::{control, math, radar, radio};
+
+// Exports
+pub use self::{
+    auction::{intercept_cost, Auction},
+    command::Command,
+    election::Election,
+    formation::FormationKeeper,
+};
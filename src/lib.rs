@@ -2,8 +2,13 @@
 
 use oort_api::prelude::*;
 
+mod behaviour;
 mod control;
+mod defence;
 mod draw;
+mod ecs;
+mod error;
+mod fleet;
 mod math;
 mod radar;
 mod radio;
@@ -11,11 +16,15 @@ mod scenario;
 mod ship;
 
 use self::{
+    error::{Error, ErrorLog},
     scenario::Scenario,
     ship::{
-        experimental::{ContactDrawer, RadarTester},
-        fighter::{DefaultFighter, Duelist},
+        cruiser::DefaultCruiser,
+        experimental::{ContactDrawer, Interceptor, RadarTester},
+        fighter::{DefaultFighter, Duelist, Skirmisher, Striker},
+        frigate::DefaultFrigate,
         missile::DefaultMissile,
+        torpedo::DefaultTorpedo,
         ShipClass,
     },
 };
@@ -24,6 +33,7 @@ use self::{
 
 pub struct Ship {
     class: ShipClass,
+    errors: ErrorLog,
 }
 
 impl Default for Ship {
@@ -36,7 +46,10 @@ impl Default for Ship {
 
 impl From<ShipClass> for Ship {
     fn from(class: ShipClass) -> Self {
-        return Self { class };
+        return Self {
+            class,
+            errors: ErrorLog::new(),
+        };
     }
 }
 
@@ -53,7 +66,31 @@ impl Ship {
             return match Self::SANDBOX_MODE {
                 "radar_test" => Self::from(ShipClass::ExRadarTester(RadarTester::new())),
                 "contact_draw" => Self::from(ShipClass::ExContactDrawer(ContactDrawer::new())),
-                _ => panic!("Error - Unknown sandbox mode"),
+                "skirmish" => match class() {
+                    Class::Fighter => Self::from(ShipClass::Fighter(Box::new(Skirmisher::new()))),
+                    Class::Missile => {
+                        Self::from(ShipClass::Missile(Box::new(DefaultMissile::new())))
+                    }
+                    _ => Self::default(),
+                },
+                "intercept" => match class() {
+                    Class::Fighter => Self::from(ShipClass::Fighter(Box::new(Interceptor::new()))),
+                    Class::Missile => {
+                        Self::from(ShipClass::Missile(Box::new(DefaultMissile::new())))
+                    }
+                    _ => Self::default(),
+                },
+                "strike" => match class() {
+                    Class::Fighter => Self::from(ShipClass::Fighter(Box::new(Striker::new()))),
+                    Class::Missile => {
+                        Self::from(ShipClass::Missile(Box::new(DefaultMissile::new())))
+                    }
+                    _ => Self::default(),
+                },
+                mode => {
+                    error::report(Error::UnknownSandboxMode(mode));
+                    Self::default()
+                }
             };
         }
 
@@ -61,11 +98,22 @@ impl Ship {
         return match class() {
             Class::Fighter => match Scenario::current() {
                 Scenario::FighterDuel => Self::from(Fighter(Box::new(Duelist::new()))),
+                // Half the fighters make attack runs while the rest dogfight.
+                Scenario::Furball => match id() % 2 {
+                    0 => Self::from(Fighter(Box::new(Skirmisher::new()))),
+                    _ => Self::from(Fighter(Box::new(Striker::new()))),
+                },
                 _ => Self::from(Fighter(Box::new(DefaultFighter::new()))),
             },
 
+            Class::Frigate => Self::from(Frigate(Box::new(DefaultFrigate::new()))),
+
+            Class::Cruiser => Self::from(Cruiser(Box::new(DefaultCruiser::new()))),
+
             Class::Missile => Self::from(Missile(Box::new(DefaultMissile::new()))),
 
+            Class::Torpedo => Self::from(Torpedo(Box::new(DefaultTorpedo::new()))),
+
             _ => Self::default(),
         };
     }
@@ -73,12 +121,18 @@ impl Ship {
     pub fn tick(&mut self) {
         match &mut self.class {
             ShipClass::Fighter(fighter) => fighter.tick(),
+            ShipClass::Frigate(frigate) => frigate.tick(),
+            ShipClass::Cruiser(cruiser) => cruiser.tick(),
             ShipClass::Missile(missile) => missile.tick(),
+            ShipClass::Torpedo(torpedo) => torpedo.tick(),
             ShipClass::ExContactDrawer(contact_drawer) => contact_drawer.tick(),
             ShipClass::ExRadarTester(radar_tester) => radar_tester.tick(),
 
             ShipClass::Unknown() => (),
         }
+
+        self.errors.collect(current_tick());
+        self.errors.draw();
     }
 }
 
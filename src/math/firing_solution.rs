@@ -1,26 +1,58 @@
 use oort_api::prelude::*;
 
 use super::{
-    kinematics::{Acceleration, Position, Velocity},
-    polynomial::{self, Roots},
+    error::Error,
+    kinematics::{Acceleration, KinematicModel, Position, Velocity},
+    polynomial,
 };
 
-/// TODO
-/// ----
-/// Actually calculate the velocity of the impact point.
-/// relative velocity of target at impact time?
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Why a target can't be hit.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Miss {
+    /// The projectile can never catch the target.
+    OutOfReach,
+
+    /// The only times the projectile and target meet are in the past.
+    Passed,
+
+    /// The equation for the time of flight is one the solver can't handle, e.g. the target is
+    /// sitting on top of the shooter.
+    Unsolvable,
+}
+
+/// Description
+/// -----------
+/// Where to aim a projectile so that it meets a target, assuming the target holds it's current
+/// acceleration until impact.
+///
+/// The position is the aim point. It's in the world frame but moves with the shooter, since the
+/// projectile inherits the shooter's velocity. The velocity and acceleration are the target's at
+/// the time of impact.
+///
+#[derive(Clone, PartialEq, Debug)]
 pub struct FiringSolution {
-    impact_time: f64,
-    impact_point: Vec2,
-    target_velocity: Vec2,
-    target_acceleration: Vec2,
+    /// Time of flight of the projectile.
+    time: f64,
+
+    /// Heading to fire along.
+    heading: f64,
+
+    aim_point: Vec2,
+
+    /// State of the target at the time of impact.
+    impact: KinematicModel,
 }
 
 ////////////////////////////////////////////////////////////////
 
 impl Position for FiringSolution {
     fn position(&self) -> Vec2 {
-        return self.impact_point;
+        return self.aim_point;
     }
 }
 
@@ -28,7 +60,7 @@ impl Position for FiringSolution {
 
 impl Velocity for FiringSolution {
     fn velocity(&self) -> Vec2 {
-        return self.target_velocity;
+        return self.impact().velocity();
     }
 }
 
@@ -36,58 +68,231 @@ impl Velocity for FiringSolution {
 
 impl Acceleration for FiringSolution {
     fn acceleration(&self) -> Vec2 {
-        return self.target_acceleration;
+        return self.impact().acceleration();
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
 impl FiringSolution {
+    /// Description
+    /// -----------
+    /// Return the earliest solution for hitting a target.
+    ///
     pub fn new<T: Velocity, U: Acceleration>(
         shooter: &T,
         projectile_speed: f64,
         target: &U,
-    ) -> Option<Self> {
+    ) -> Result<Self, Error> {
+        let mut solutions = Self::all(shooter, projectile_speed, target)?;
+        return Ok(solutions.swap_remove(0));
+    }
+
+    /// Description
+    /// -----------
+    /// Return every solution for hitting a target, earliest first.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `shooter` - Ship firing the projectile.
+    /// * `projectile_speed` - Speed of the projectile relative to the shooter.
+    /// * `target` - Target to hit.
+    ///
+    /// Returns
+    /// -------
+    /// At least one solution, or the reason there are none.
+    ///
+    pub fn all<T: Velocity, U: Acceleration>(
+        shooter: &T,
+        projectile_speed: f64,
+        target: &U,
+    ) -> Result<Vec<Self>, Error> {
         // Use relative velocity to account for own movement.
         let tarpos = target.position_relative_to(shooter);
         let tarvel = target.velocity_relative_to(shooter);
         let taracc = target.acceleration();
 
+        // |p + vt + at^2/2| = st
         let a = taracc.dot(taracc) / 4.0;
         let b = taracc.dot(tarvel);
         let c = tarvel.dot(tarvel) + taracc.dot(tarpos) - projectile_speed.powf(2.0);
         let d = 2.0 * tarvel.dot(tarpos);
         let e = tarpos.dot(tarpos);
 
-        let roots = polynomial::find_roots_quartic(a, b, c, d, e);
-
-        // We always expect there to be four roots.
-        let impact_time = if let Roots::Four(roots) = roots {
-            debug!("Root0: {}", roots[0]);
-            debug!("Root1: {}", roots[1]);
-            debug!("Root2: {}", roots[2]);
-            debug!("Root3: {}", roots[3]);
-            roots[2]
-        } else if let Roots::Two(roots) = roots {
-            debug!("Root0: {}", roots[0]);
-            debug!("Root1: {}", roots[1]);
-            roots[0]
+        if e == 0.0 {
+            return Err(Miss::Unsolvable.into());
+        }
+
+        let times: Vec<f64> = if a == 0.0 {
+            // Not accelerating so the quartic collapses to a quadratic.
+            polynomial::find_roots_quadratic(c, d, e).as_ref().to_vec()
+        } else if b == 0.0 && d == 0.0 {
+            // Only even powers, so solve for t^2 instead.
+            polynomial::find_roots_quadratic(a, c, e)
+                .as_ref()
+                .iter()
+                .filter(|squared| **squared >= 0.0)
+                .map(|squared| squared.sqrt())
+                .collect()
         } else {
-            f64::NAN
+            polynomial::find_roots_quartic(a, b, c, d, e)
+                .as_ref()
+                .to_vec()
         };
 
-        if impact_time.is_nan() {
-            return None;
+        if times.is_empty() {
+            return Err(Miss::OutOfReach.into());
+        }
+
+        let mut solutions: Vec<Self> = times
+            .into_iter()
+            .filter(|time| *time > 0.0)
+            .map(|time| Self::at(shooter, target, tarvel, time))
+            .collect();
+
+        if solutions.is_empty() {
+            return Err(Miss::Passed.into());
         }
 
-        let impact_point =
-            target.position() + ((tarvel * impact_time) + (0.5 * taracc * impact_time.powf(2.0)));
+        solutions.sort_by(|a, b| a.time.total_cmp(&b.time));
+        return Ok(solutions);
+    }
+
+    /// Description
+    /// -----------
+    /// Build the solution for a projectile meeting the target after a given time of flight.
+    ///
+    fn at<T: Position, U: Acceleration>(shooter: &T, target: &U, tarvel: Vec2, time: f64) -> Self {
+        let taracc = target.acceleration();
+        let drift = 0.5 * taracc * time.powf(2.0);
+
+        let aim_point = target.position() + (tarvel * time) + drift;
+        let impact = KinematicModel::new(
+            target.position() + (target.velocity() * time) + drift,
+            target.velocity() + (taracc * time),
+            taracc,
+        );
+
+        return Self {
+            time,
+            heading: (aim_point - shooter.position()).angle(),
+            aim_point,
+            impact,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return the time the projectile takes to reach the impact point.
+    ///
+    pub fn time(&self) -> f64 {
+        return self.time;
+    }
+
+    /// Description
+    /// -----------
+    /// Return the heading to fire along.
+    ///
+    pub fn aim_heading(&self) -> f64 {
+        return self.heading;
+    }
 
-        return Some(Self {
-            impact_time,
-            impact_point,
-            target_velocity: target.velocity(),
-            target_acceleration: target.acceleration(),
-        });
+    /// Description
+    /// -----------
+    /// Return the predicted state of the target at the time of impact.
+    ///
+    pub fn impact(&self) -> &KinematicModel {
+        return &self.impact;
     }
 }
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn still(position: Vec2) -> KinematicModel {
+        return KinematicModel::new(position, vec2(0.0, 0.0), vec2(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_stationary_target() {
+        let shooter = still(vec2(0.0, 0.0));
+        let target = still(vec2(1000.0, 0.0));
+
+        let solution = FiringSolution::new(&shooter, 1000.0, &target).unwrap();
+        assert!((solution.time() - 1.0).abs() < 1e-6);
+        assert!(solution.aim_heading().abs() < 1e-6);
+        assert!(solution.position().distance(target.position()) < 1e-6);
+    }
+
+    #[test]
+    fn test_impact_state() {
+        let shooter = still(vec2(0.0, 0.0));
+        let target = KinematicModel::new(vec2(1000.0, 0.0), vec2(0.0, 100.0), vec2(0.0, 10.0));
+
+        let solution = FiringSolution::new(&shooter, 1000.0, &target).unwrap();
+        let time = solution.time();
+        assert!(time > 0.0);
+
+        // The projectile covers the distance to the impact point in the time of flight.
+        let impact = solution.impact();
+        assert!((impact.position().length() - (1000.0 * time)).abs() < 1e-3);
+        assert!((impact.velocity().y - (100.0 + (10.0 * time))).abs() < 1e-6);
+        assert_eq!(solution.velocity(), impact.velocity());
+        assert_eq!(solution.acceleration(), vec2(0.0, 10.0));
+
+        let heading = impact.position().angle();
+        assert!((solution.aim_heading() - heading).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_moving_shooter() {
+        // Shooter's velocity carries over to the projectile, so the aim point leads the impact
+        // point by however far the shooter travels.
+        let shooter = KinematicModel::new(vec2(0.0, 0.0), vec2(0.0, 100.0), vec2(0.0, 0.0));
+        let target = still(vec2(1000.0, 0.0));
+
+        let solution = FiringSolution::new(&shooter, 1000.0, &target).unwrap();
+        let offset = solution.impact().position() - solution.position();
+        assert!(offset.distance(shooter.velocity() * solution.time()) < 1e-6);
+        assert!(solution.aim_heading().sin() < 0.0);
+    }
+
+    #[test]
+    fn test_all() {
+        // A target that slows to a stop and then accelerates away gets hit on the way in, and
+        // again once a projectile catches up with it on the way out.
+        let shooter = still(vec2(0.0, 0.0));
+        let target = KinematicModel::new(vec2(1000.0, 0.0), vec2(-300.0, 0.0), vec2(200.0, 0.0));
+
+        let solutions = FiringSolution::all(&shooter, 1000.0, &target).unwrap();
+        assert!(solutions.len() > 1);
+        assert!(solutions.iter().all(|solution| solution.time() > 0.0));
+        assert!(solutions.windows(2).all(|w| w[0].time() < w[1].time()));
+
+        let first = FiringSolution::new(&shooter, 1000.0, &target).unwrap();
+        assert_eq!(first, solutions[0]);
+    }
+
+    #[test]
+    fn test_miss() {
+        let shooter = still(vec2(0.0, 0.0));
+
+        // Crossing faster than the projectile.
+        let target = KinematicModel::new(vec2(1000.0, 0.0), vec2(0.0, 2000.0), vec2(0.0, 0.0));
+        let error = FiringSolution::new(&shooter, 1000.0, &target).unwrap_err();
+        assert_eq!(error, Error::Miss(Miss::OutOfReach));
+
+        // Running away faster than the projectile, so they only met in the past.
+        let target = KinematicModel::new(vec2(1000.0, 0.0), vec2(2000.0, 0.0), vec2(0.0, 0.0));
+        let error = FiringSolution::new(&shooter, 1000.0, &target).unwrap_err();
+        assert_eq!(error, Error::Miss(Miss::Passed));
+
+        let error = FiringSolution::new(&shooter, 1000.0, &shooter).unwrap_err();
+        assert_eq!(error, Error::Miss(Miss::Unsolvable));
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -54,6 +54,30 @@ impl Ellipse {
             };
         };
     }
+
+    /// Description
+    /// -----------
+    /// Return the distance from the centre to the furthest point on the ellipse.
+    ///
+    pub fn semi_major_axis(&self) -> f64 {
+        return self.height / 2.0;
+    }
+
+    /// Description
+    /// -----------
+    /// Return half the width of the ellipse's shadow when projected on to a line at the given
+    /// angle.
+    ///
+    pub fn extent(&self, angle: f64) -> f64 {
+        let angle = angle - self.orientation;
+        let semi_major_axis = self.height / 2.0;
+        let semi_minor_axis = self.width / 2.0;
+
+        return f64::sqrt(
+            (semi_major_axis * f64::cos(angle)).powi(2)
+                + (semi_minor_axis * f64::sin(angle)).powi(2),
+        );
+    }
 }
 
 ////////////////////////////////////////////////////////////////
@@ -205,6 +229,20 @@ mod tests {
         assert_eq!(ellipse.radius(-FRAC_PI_4), width / 2.0);
     }
 
+    #[test]
+    fn test_extent() {
+        let height = 10.0;
+        let width = 5.0;
+        let ellipse = Ellipse::new(&vec2(0.0, 0.0), 0.0, width, height);
+
+        assert!((ellipse.extent(0.0) - (height / 2.0)).abs() < 1e-9);
+        assert!((ellipse.extent(FRAC_PI_2) - (width / 2.0)).abs() < 1e-9);
+
+        // The shadow of a tilted ellipse is wider than it's radius in the same direction.
+        let ellipse = Ellipse::new(&vec2(0.0, 0.0), FRAC_PI_4, width, height);
+        assert!(ellipse.extent(0.0) > ellipse.radius(0.0));
+    }
+
     #[test]
     fn test_contains() {
         let ellipse = Ellipse::new(&Point::new(0.0, 0.0), 0.0, 5.0, 10.0);
@@ -1,12 +1,12 @@
 use oort_api::prelude::*;
 
 use super::{
+    error::Error,
     kinematics::{Acceleration, Position},
     polynomial::{self, Roots},
 };
 
 pub struct Intercept {
-    time: f64,
     point: Vec2,
 }
 
@@ -25,7 +25,7 @@ impl Intercept {
         vessel: &T,
         acceleration: f64,
         target: &U,
-    ) -> Self {
+    ) -> Result<Self, Error> {
         let relpos = target.position_relative_to(vessel);
         let relvel = target.velocity_relative_to(vessel);
 
@@ -45,13 +45,12 @@ impl Intercept {
             debug!("2 roots: {:?}", roots);
             roots[1]
         } else {
-            debug!("? roots");
-            f64::NAN
+            return Err(Error::NoSolution);
         };
 
         let point =
             target.position() + (relvel * time) + (0.5 * target.acceleration() * time.powf(2.0));
 
-        return Self { time, point };
+        return Ok(Self { point });
     }
 }
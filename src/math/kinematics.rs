@@ -18,6 +18,7 @@ impl Position for Circle {
 ////////////////////////////////////////////////////////////////
 
 // Make each field a closure?
+#[derive(Clone, PartialEq, Debug)]
 pub struct KinematicModel {
     position: Vec2,
     velocity: Vec2,
@@ -42,6 +43,16 @@ impl Acceleration for KinematicModel {
     }
 }
 
+impl KinematicModel {
+    pub fn new(position: Vec2, velocity: Vec2, acceleration: Vec2) -> Self {
+        return Self {
+            position,
+            velocity,
+            acceleration,
+        };
+    }
+}
+
 impl<T: Acceleration> From<&T> for KinematicModel {
     fn from(value: &T) -> Self {
         return Self {
@@ -94,10 +105,6 @@ pub trait Velocity: Position {
         return self.velocity() - other.velocity();
     }
 
-    fn speed(&self) -> f64 {
-        return self.velocity().length();
-    }
-
     fn speed_relative_to<T: Velocity>(&self, other: &T) -> f64 {
         return (self.velocity() - other.velocity()).length();
     }
@@ -140,22 +147,6 @@ pub trait Acceleration: Velocity {
     fn velocity_after(&self, seconds: f64) -> Vec2 {
         return self.velocity() + (self.acceleration() * seconds);
     }
-
-    fn orbital_acceleration_to<T: Position>(&self, other: &T) -> f64 {
-        let vector_prograde = self.position_relative_to(other).rotate(-PI / 4.0);
-        let angle_prograde = angle_diff(vector_prograde.angle(), self.acceleration().angle());
-
-        let acceleration_prograde = self.acceleration_magnitude() * f64::cos(angle_prograde);
-
-        let vector_radial = self.position_relative_to(other);
-        let angle_radial = angle_diff(vector_radial.angle(), self.acceleration().angle());
-
-        let velocity_radial = self.speed() * f64::cos(angle_radial);
-        let velocity_prograde = self.speed() * f64::cos(angle_prograde);
-
-        return (self.distance_to(other) * acceleration_prograde)
-            + (2.0 * velocity_radial * velocity_prograde);
-    }
 }
 
 ////////////////////////////////////////////////////////////////
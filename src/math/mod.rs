@@ -5,7 +5,10 @@ pub mod kinematics;
 mod polynomial;
 
 // Imports.
-use super::draw;
+use super::{draw, error};
 
 // Exports.
-pub use self::{firing_solution::FiringSolution, intercept::Intercept};
+pub use self::{
+    firing_solution::{FiringSolution, Miss},
+    intercept::Intercept,
+};
@@ -14,6 +14,16 @@ pub trait ContactBoard<S: RadarContact, T: TrackedRadarContact>: IntoIterator {
     fn get(&self, id: Self::ID) -> Option<&Contact<S, T>>;
     fn remove(&mut self, id: Self::ID) -> Option<Contact<S, T>>;
 
+    /// Description
+    /// -----------
+    /// Remove every contact matching the predicate.
+    ///
+    /// Returns
+    /// -------
+    /// The IDs of the removed contacts.
+    ///
+    fn remove_if<F: FnMut(&Contact<S, T>) -> bool>(&mut self, predicate: F) -> Vec<Self::ID>;
+
     fn iter(&self) -> Self::Iter<'_>;
     fn count(&self) -> usize;
 
@@ -1,6 +1,10 @@
 use std::collections::BTreeMap;
 
-use crate::{draw::Colour, math::geometry::Shape, radar::contacts::Contact};
+use crate::{
+    draw::Colour,
+    math::geometry::{Ellipse, Shape},
+    radar::contacts::Contact,
+};
 
 use super::{
     contacts::{RadarContact, TrackedRadarContact},
@@ -43,15 +47,21 @@ impl<S: RadarContact, T: TrackedRadarContact> IntoIterator for UniqueContactBoar
 impl<S, T> ContactBoard<S, T> for UniqueContactBoard<S, T>
 where
     S: RadarContact,
+    S::AreaShape: From<Ellipse>,
     T: TrackedRadarContact<AreaShape = S::AreaShape>,
 {
     type ID = usize;
-    type Iter<'a> = std::collections::btree_map::Iter<'a, usize, Contact<S, T>> where T: 'a, S: 'a;
+    type Iter<'a>
+        = std::collections::btree_map::Iter<'a, usize, Contact<S, T>>
+    where
+        T: 'a,
+        S: 'a;
 
     fn add(&mut self, contact: Contact<S, T>) -> Self::ID {
         return match contact {
             Contact::Search(contact) => self.add_search_contact(Contact::Search(contact)),
             Contact::Tracked(contact) => self.add_tracked_contact(Contact::Tracked(contact)),
+            Contact::Remote(contact) => self.add_remote_contact(Contact::Remote(contact)),
         };
     }
 
@@ -67,6 +77,21 @@ where
         return self.0.remove(&id);
     }
 
+    fn remove_if<F: FnMut(&Contact<S, T>) -> bool>(&mut self, mut predicate: F) -> Vec<usize> {
+        let ids: Vec<usize> = self
+            .0
+            .iter()
+            .filter(|(_, c)| predicate(c))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in ids.iter() {
+            self.0.remove(id);
+        }
+
+        return ids;
+    }
+
     fn count(&self) -> usize {
         return self.0.len();
     }
@@ -80,6 +105,7 @@ where
             let colour = match contact {
                 Contact::Search(_) => Colour::Red,
                 Contact::Tracked(_) => Colour::Green,
+                Contact::Remote(_) => Colour::Teal,
             };
 
             contact.get_area_after(contact.time_elapsed()).draw(colour);
@@ -92,6 +118,7 @@ where
 impl<S, T> UniqueContactBoard<S, T>
 where
     S: RadarContact,
+    S::AreaShape: From<Ellipse>,
     T: TrackedRadarContact<AreaShape = S::AreaShape>,
 {
     fn add_search_contact(&mut self, contact: Contact<S, T>) -> usize {
@@ -152,6 +179,49 @@ where
         self.0.insert(id, contact);
         return id;
     }
+
+    fn add_remote_contact(&mut self, contact: Contact<S, T>) -> usize {
+        // Find any contacts matching class and position.
+        let contact_area = contact.get_area_after(contact.time_elapsed());
+
+        let matches = self.0.iter();
+        let matches = matches.filter(|(_, c)| c.class() == contact.class());
+        let matches = matches.map(|(id, c)| (id, c, c.get_area_after(c.time_elapsed())));
+        let matches =
+            matches.filter(|(_, c, area)| area.contains(&contact) || contact_area.contains(*c));
+
+        // Our own radar is more reliable than another ship's report.
+        if let Some((id, ..)) = matches
+            .clone()
+            .find(|(_, c, _)| !matches!(c, Contact::Remote(_)))
+        {
+            return *id;
+        }
+
+        // Keep whichever report is the most recent.
+        if let Some((id, ..)) = matches
+            .clone()
+            .find(|(_, c, _)| c.time_elapsed() < contact.time_elapsed())
+        {
+            return *id;
+        }
+
+        // Replace the first match with the new contact and remove all other matches.
+        let matches = matches.map(|(id, _, _)| *id);
+        let matches = matches.collect::<Vec<usize>>();
+        for id in matches.iter().skip(1) {
+            self.0.remove(id);
+        }
+
+        let id = matches
+            .first()
+            .cloned()
+            .or(self.0.last_key_value().map(|(k, _)| k + 1))
+            .unwrap_or(0);
+
+        self.0.insert(id, contact);
+        return id;
+    }
 }
 
 ////////////////////////////////////////////////////////////////
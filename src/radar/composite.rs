@@ -6,7 +6,7 @@ use crate::math::kinematics::{Acceleration, Position};
 
 use super::{
     board::ContactBoard,
-    contacts::Contact,
+    contacts::{Contact, RadarContact, RemoteContact},
     control::{SearchRadarControl, TrackingRadarControl},
 };
 
@@ -69,15 +69,29 @@ where
         let tracked_id = self.tracked.iter().nth(self.track_index).cloned();
 
         if let Some(id) = tracked_id {
-            let updated_contact = self
-                .contacts
-                .remove(id)
-                .map(|c| match c {
-                    Contact::Search(contact) => TrackingRadar::Contact::from(contact),
-                    Contact::Tracked(contact) => contact,
-                })
-                .and_then(|c| self.track.scan(emitter, c))
-                .map(Contact::Tracked);
+            let updated_contact = match self.contacts.remove(id) {
+                Some(Contact::Search(contact)) => self
+                    .track
+                    .scan(emitter, TrackingRadar::Contact::from(contact)),
+                Some(Contact::Tracked(contact)) => self.track.scan(emitter, contact),
+                Some(Contact::Remote(contact)) => {
+                    let acquired = self
+                        .track
+                        .acquire(emitter)
+                        .filter(|c| c.class() == contact.class());
+
+                    // We've only been told about this contact. Keep looking for it where it was
+                    // reported until we find it or the report expires.
+                    if acquired.is_none() {
+                        self.contacts.update(id, Contact::Remote(contact));
+                        return;
+                    }
+
+                    acquired
+                }
+                None => None,
+            }
+            .map(Contact::Tracked);
 
             if let Some(contact) = updated_contact {
                 self.contacts.update(id, contact)
@@ -104,6 +118,7 @@ where
                 Some(Contact::Search(contact)) => self
                     .track
                     .adjust(emitter, &TrackingRadar::Contact::from(contact)),
+                Some(Contact::Remote(contact)) => self.track.adjust(emitter, contact),
                 None => debug!("!!! => contact not found"),
             }
         } else {
@@ -133,6 +148,34 @@ where
     pub fn stop_tracking(&mut self, id: Board::ID) {
         self.tracked.remove(&id);
     }
+
+    /// Description
+    /// -----------
+    /// Add a contact reported by another ship.
+    ///
+    pub fn fuse(&mut self, contact: RemoteContact) -> Board::ID {
+        return self.contacts.add(Contact::Remote(contact));
+    }
+
+    /// Description
+    /// -----------
+    /// Remove reported contacts that haven't been confirmed by our own radar or reported again
+    /// recently.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `max_age` - Time in seconds a report remains valid for.
+    ///
+    pub fn expire_remote(&mut self, max_age: f64) {
+        let expired = self.contacts.remove_if(|c| match c {
+            Contact::Remote(contact) => contact.time_elapsed() > max_age,
+            _ => false,
+        });
+
+        for id in expired {
+            self.tracked.remove(&id);
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////
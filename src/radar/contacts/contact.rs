@@ -1,8 +1,13 @@
 use oort_api::prelude::{Class, Vec2};
 
-use crate::math::{geometry::Ellipse, kinematics::Position};
+use crate::math::{
+    geometry::Ellipse,
+    kinematics::{Position, Velocity},
+};
 
-use super::{interface::RadarContact, SearchContact, TrackedContact, TrackedRadarContact};
+use super::{
+    interface::RadarContact, RemoteContact, SearchContact, TrackedContact, TrackedRadarContact,
+};
 
 ////////////////////////////////////////////////////////////////
 
@@ -10,6 +15,9 @@ use super::{interface::RadarContact, SearchContact, TrackedContact, TrackedRadar
 pub enum Contact<S: RadarContact, T: TrackedRadarContact> {
     Search(S),
     Tracked(T),
+
+    /// Seen by another ship and reported over the radio.
+    Remote(RemoteContact),
 }
 
 ////////////////////////////////////////////////////////////////
@@ -19,17 +27,44 @@ impl<S: RadarContact, T: TrackedRadarContact> Position for Contact<S, T> {
         return match self {
             Self::Search(contact) => contact.position(),
             Self::Tracked(contact) => contact.position(),
+            Self::Remote(contact) => contact.position(),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl<S: RadarContact, T: TrackedRadarContact> Velocity for Contact<S, T> {
+    fn velocity(&self) -> Vec2 {
+        return match self {
+            Self::Search(contact) => contact.velocity(),
+            Self::Tracked(contact) => contact.velocity(),
+            Self::Remote(contact) => contact.velocity(),
         };
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl<S: RadarContact, T: TrackedRadarContact<AreaShape = S::AreaShape>> Contact<S, T> {
+impl<S, T> Contact<S, T>
+where
+    S: RadarContact,
+    S::AreaShape: From<Ellipse>,
+    T: TrackedRadarContact<AreaShape = S::AreaShape>,
+{
+    pub fn time(&self) -> f64 {
+        return match self {
+            Self::Search(contact) => contact.time(),
+            Self::Tracked(contact) => contact.time(),
+            Self::Remote(contact) => contact.time(),
+        };
+    }
+
     pub fn time_elapsed(&self) -> f64 {
         return match self {
             Self::Search(contact) => contact.time_elapsed(),
             Self::Tracked(contact) => contact.time_elapsed(),
+            Self::Remote(contact) => contact.time_elapsed(),
         };
     }
 
@@ -37,6 +72,7 @@ impl<S: RadarContact, T: TrackedRadarContact<AreaShape = S::AreaShape>> Contact<
         return match self {
             Self::Search(contact) => contact.class(),
             Self::Tracked(contact) => contact.class(),
+            Self::Remote(contact) => contact.class(),
         };
     }
 
@@ -44,6 +80,7 @@ impl<S: RadarContact, T: TrackedRadarContact<AreaShape = S::AreaShape>> Contact<
         return match self {
             Self::Search(contact) => contact.get_area_after(time),
             Self::Tracked(contact) => contact.get_area_after(time),
+            Self::Remote(contact) => S::AreaShape::from(contact.get_area_after(time)),
         };
     }
 }
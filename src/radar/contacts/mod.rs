@@ -1,6 +1,7 @@
 mod contact;
 mod error;
 mod interface;
+mod remote;
 mod search;
 mod track;
 
@@ -11,6 +12,7 @@ use super::emitter;
 pub use self::{
     contact::Contact,
     interface::{RadarContact, TrackedRadarContact},
+    remote::RemoteContact,
     search::SearchContact,
     track::TrackedContact,
 };
@@ -0,0 +1,151 @@
+use oort_api::prelude::*;
+
+use crate::math::geometry::{Ellipse, EllipticalShape, Shape, Vector};
+use crate::math::kinematics::{Acceleration, Position, Velocity};
+use crate::ship::stats::MaxAcceleration;
+
+use super::interface::RadarContact;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A contact seen by another ship's radar and reported to us over the radio.
+///
+/// We know nothing about how the reporter detected it so the possible positions are modeled as a
+/// circle, sized by the reporter's own estimate of it's error.
+///
+/// Like our own contacts, the position is where it was when seen. Reports can be several seconds
+/// old by the time they arrive, so use the area after `time_elapsed()` to find where it is now.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct RemoteContact {
+    pub(super) reporter: u16,
+    pub(super) time: f64,
+
+    pub(super) class: Class,
+    pub(super) position: Vec2,
+    pub(super) velocity: Vec2,
+
+    pub(super) error: f64,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl RemoteContact {
+    pub fn new(
+        reporter: u16,
+        time: f64,
+        class: Class,
+        position: Vec2,
+        velocity: Vec2,
+        error: f64,
+    ) -> Self {
+        return Self {
+            reporter,
+            time,
+
+            class,
+            position,
+            velocity,
+
+            error,
+        };
+    }
+
+    pub fn reporter(&self) -> u16 {
+        return self.reporter;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for RemoteContact {
+    fn position(&self) -> Vec2 {
+        return self.position;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for RemoteContact {
+    fn velocity(&self) -> Vec2 {
+        return self.velocity;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for RemoteContact {
+    fn acceleration(&self) -> Vec2 {
+        // Not reported, assume it's holding course.
+        return vec2(0.0, 0.0);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl RadarContact for RemoteContact {
+    type AreaShape = Ellipse;
+
+    fn time(&self) -> f64 {
+        return self.time;
+    }
+
+    fn time_elapsed(&self) -> f64 {
+        return current_time() - self.time;
+    }
+
+    fn class(&self) -> Class {
+        return self.class;
+    }
+
+    fn get_area_after(&self, time: f64) -> Self::AreaShape {
+        let mut area = Ellipse::new(&self.position, 0.0, self.error * 2.0, self.error * 2.0);
+
+        // Move the area according to it's approximate velocity.
+        area.translate(&Vector::from(self.velocity * time));
+
+        // Expand the area to take into account possible accleration.
+        // Classes without stats can't be expanded for acceleration.
+        let max_accel = MaxAcceleration::try_from(self.class).map_or(0.0, |a| a.magnitude());
+        area.expand(0.5 * max_accel * time.powf(2.0));
+
+        return area;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area() {
+        let contact = RemoteContact::new(
+            1,
+            0.0,
+            Class::Fighter,
+            vec2(1000.0, 0.0),
+            vec2(100.0, 0.0),
+            50.0,
+        );
+
+        // Position and area both start from where the contact was seen.
+        let area = contact.get_area_after(0.0);
+        assert_eq!(contact.position(), vec2(1000.0, 0.0));
+        assert!(area.contains(&contact.position()));
+        assert!(area.contains(&vec2(1049.0, 0.0)));
+        assert!(area.contains(&vec2(1000.0, -49.0)));
+        assert!(!area.contains(&vec2(1051.0, 0.0)));
+
+        // The area follows the contact and grows the older the report gets.
+        let area = contact.get_area_after(2.0);
+        assert!(area.contains(&vec2(1200.0, 0.0)));
+        assert!(area.contains(&vec2(1200.0, 51.0)));
+        assert!(!area.contains(&vec2(1000.0, 0.0)));
+    }
+}
+
+////////////////////////////////////////////////////////////////
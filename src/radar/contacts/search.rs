@@ -100,8 +100,9 @@ impl RadarContact for SearchContact {
         area.translate(&Vector::from(self.velocity * time));
 
         // Expand the area to take into account velocity error and possible accleration.
-        let max_accel = MaxAcceleration::from(self.class);
-        area.expand(self.error.velocity * time + (0.5 * max_accel.magnitude() * time.powf(2.0)));
+        // Classes without stats can't be expanded for acceleration.
+        let max_accel = MaxAcceleration::try_from(self.class).map_or(0.0, |a| a.magnitude());
+        area.expand(self.error.velocity * time + (0.5 * max_accel * time.powf(2.0)));
 
         return area;
     }
@@ -210,10 +210,10 @@ impl RadarContact for TrackedContact {
 
         // Expand the area to take into account velocity error and possible accleration.
         // TODO: should be using the actual acceleration here.
-        let max_accel = MaxAcceleration::from(self.class);
+        // Classes without stats can't be expanded for acceleration.
+        let max_accel = MaxAcceleration::try_from(self.class).map_or(0.0, |a| a.magnitude());
         area.expand(
-            self.error.back().unwrap().velocity * time
-                + (0.5 * max_accel.magnitude() * time.powf(2.0)),
+            self.error.back().unwrap().velocity * time + (0.5 * max_accel * time.powf(2.0)),
         );
 
         return area;
@@ -62,7 +62,14 @@ pub trait TrackingRadarControl: Default {
     type Contact: TrackedRadarContact;
 
     fn scan<T: Position>(&mut self, emitter: &T, target: Self::Contact) -> Option<Self::Contact>;
-    fn adjust<T: Acceleration>(&self, emitter: &T, target: &Self::Contact);
+
+    /// Description
+    /// -----------
+    /// Start a new track from whatever is in the radar beam.
+    ///
+    fn acquire<T: Position>(&mut self, emitter: &T) -> Option<Self::Contact>;
+
+    fn adjust<T: Acceleration, C: RadarContact>(&self, emitter: &T, target: &C);
 }
 
 ////////////////////////////////////////////////////////////////
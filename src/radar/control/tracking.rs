@@ -55,11 +55,16 @@ impl TrackingRadarControl for TrackingRadar {
         });
     }
 
+    fn acquire<E: Position>(&mut self, emitter: &E) -> Option<Self::Contact> {
+        let emitter = Emitter::new(emitter, self);
+        return self.get_scan().map(|s| TrackedContact::new(&s, &emitter));
+    }
+
     /// Description
     /// -----------
     /// Adjust the radar beam for the next tick.
     ///
-    fn adjust<T: Acceleration>(&self, emitter: &T, target: &TrackedContact) {
+    fn adjust<T: Acceleration, C: RadarContact>(&self, emitter: &T, target: &C) {
         let time_elapsed = target.time_elapsed() + TICK_LENGTH;
 
         let area = target.get_area_after(time_elapsed);
@@ -3,9 +3,10 @@ mod composite;
 pub mod contacts;
 mod control;
 mod emitter;
+mod picture;
 
 // Imports
-use super::{draw, math, ship};
+use super::{draw, math, radio, ship};
 
 use self::{
     board::UniqueContactBoard,
@@ -13,6 +14,8 @@ use self::{
 };
 
 // Exports
+pub use self::{composite::Error, picture::TacticalPicture};
+
 pub type CompositeRadar = composite::CompositeRadar<
     SearchRadar,
     TrackingRadar,
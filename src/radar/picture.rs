@@ -0,0 +1,211 @@
+use oort_api::prelude::*;
+
+use crate::math::kinematics::{Position, Velocity};
+
+use super::{
+    board::ContactBoard,
+    contacts::{Contact, RemoteContact},
+    radio::ContactReport,
+    CompositeRadar,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Time after which a reported contact is no longer worth chasing.
+const MAX_REPORT_AGE: f64 = 3.0; // s
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Shares radar contacts with the rest of the team so that every ship can see what any one of
+/// them can.
+///
+/// Only contacts seen by our own radar are reported. Passing on other ship's reports would echo
+/// them around the team forever.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct TacticalPicture {
+    reporter: u16,
+    next: usize,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl TacticalPicture {
+    pub fn new(reporter: u16) -> Self {
+        return Self { reporter, next: 0 };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl TacticalPicture {
+    /// Description
+    /// -----------
+    /// Create a report for the next of our own contacts. Each call reports a different contact so
+    /// that all of them eventually reach the rest of the team.
+    ///
+    pub fn next_report(&mut self, radar: &CompositeRadar) -> Option<ContactReport> {
+        let local: Vec<_> = radar
+            .contacts
+            .iter()
+            .filter(|(_, c)| !matches!(c, Contact::Remote(_)))
+            .collect();
+
+        if local.is_empty() {
+            return None;
+        }
+
+        let (id, contact) = local[self.next % local.len()];
+        self.next = self.next.wrapping_add(1);
+
+        return Some(ContactReport {
+            reporter: self.reporter,
+            contact: *id as u16,
+            class: contact.class(),
+            position: contact.position(),
+            velocity: contact.velocity(),
+            error: contact.get_area_after(0.0).semi_major_axis(),
+            time: contact.time(),
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Add a contact reported by another ship to our radar's contacts.
+    ///
+    /// Returns
+    /// -------
+    /// False if the report was too old to be used.
+    ///
+    pub fn fuse(&self, radar: &mut CompositeRadar, report: &ContactReport) -> bool {
+        if report.reporter == self.reporter || current_time() - report.time > MAX_REPORT_AGE {
+            return false;
+        }
+
+        radar.fuse(RemoteContact::new(
+            report.reporter,
+            report.time,
+            report.class,
+            report.position,
+            report.velocity,
+            report.error_radius(),
+        ));
+
+        return true;
+    }
+
+    /// Description
+    /// -----------
+    /// Remove reported contacts that are too old to be used.
+    ///
+    pub fn expire(&self, radar: &mut CompositeRadar) {
+        radar.expire_remote(MAX_REPORT_AGE);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::radar::{
+        board::UniqueContactBoard,
+        contacts::{SearchContact, TrackedContact},
+        emitter::Emitter,
+    };
+
+    use super::*;
+
+    fn radar() -> CompositeRadar {
+        return CompositeRadar::new(UniqueContactBoard::new());
+    }
+
+    fn search_contact(position: Vec2) -> Contact<SearchContact, TrackedContact> {
+        let scan = ScanResult {
+            class: Class::Fighter,
+            position,
+            velocity: vec2(0.0, 0.0),
+            rssi: 50.0,
+            snr: 50.0,
+        };
+
+        let emitter = Emitter {
+            position: position - vec2(100.0, 0.0),
+            min_distance: 0.0,
+            max_distance: 1000.0,
+            heading: 0.0,
+            width: std::f64::consts::FRAC_PI_4,
+        };
+
+        return Contact::Search(SearchContact::new(0.0, &emitter, &scan));
+    }
+
+    fn report(reporter: u16, position: Vec2, time: f64) -> ContactReport {
+        return ContactReport {
+            reporter,
+            contact: 0,
+            class: Class::Fighter,
+            position,
+            velocity: vec2(0.0, 0.0),
+            error: 20.0,
+            time,
+        };
+    }
+
+    #[test]
+    fn test_reports_cycle_local_contacts() {
+        let mut radar = radar();
+        radar.contacts.add(search_contact(vec2(0.0, 0.0)));
+        radar.contacts.add(search_contact(vec2(5000.0, 0.0)));
+
+        let mut picture = TacticalPicture::new(1);
+        assert!(picture.fuse(&mut radar, &report(2, vec2(-5000.0, 0.0), 0.0)));
+        assert_eq!(radar.contacts.count(), 3);
+
+        // Only our own contacts are reported.
+        let first = picture.next_report(&radar).unwrap();
+        let second = picture.next_report(&radar).unwrap();
+        let third = picture.next_report(&radar).unwrap();
+
+        assert_eq!(first.reporter, 1);
+        assert_ne!(first.contact, second.contact);
+        assert_eq!(first, third);
+        assert!(first.position.x >= 0.0 && second.position.x >= 0.0);
+    }
+
+    #[test]
+    fn test_fuse() {
+        let mut radar = radar();
+        let picture = TacticalPicture::new(1);
+
+        // Reports matching a contact we can see ourselves are dropped in favour of our own.
+        radar.contacts.add(search_contact(vec2(0.0, 0.0)));
+        assert!(picture.fuse(&mut radar, &report(2, vec2(5.0, 0.0), 0.0)));
+        assert!(matches!(
+            radar.contacts.iter().next(),
+            Some((_, Contact::Search(_)))
+        ));
+
+        // Repeated reports of the same contact replace each other.
+        assert!(picture.fuse(&mut radar, &report(2, vec2(3000.0, 0.0), -0.5)));
+        assert!(picture.fuse(&mut radar, &report(3, vec2(3010.0, 0.0), 0.0)));
+        assert_eq!(radar.contacts.count(), 2);
+
+        let remote = radar
+            .contacts
+            .iter()
+            .find_map(|(_, c)| match c {
+                Contact::Remote(remote) => Some(remote.clone()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(remote.reporter(), 3);
+
+        // Old reports are ignored.
+        assert!(!picture.fuse(&mut radar, &report(2, vec2(-3000.0, 0.0), -10.0)));
+        assert_eq!(radar.contacts.count(), 2);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,407 @@
+use std::collections::BTreeMap;
+
+use super::error::Error;
+
+////////////////////////////////////////////////////////////////
+
+/// Key shared by every ship on our team. It's compiled into the bundle so ships never need to
+/// exchange it over the radio, where the enemy could hear it.
+/// Change this before submitting, anyone running a copy of this code with the same key can forge
+/// our messages.
+const TEAM_SECRET: [u64; 2] = [0x6f6f_7274_2d61_6921, 0x5d3c_a91e_07b4_f268];
+
+/// Size of a radio message. Shorter messages are zero padded by the radio.
+const MESSAGE_SIZE: usize = 32;
+
+/// Sender ID (2 bytes), tick sent on (2 bytes) and MAC (4 bytes).
+const HEADER_SIZE: usize = 8;
+
+/// Oldest a message can be and still be accepted. Messages are normally received on the tick
+/// after they're sent.
+const MAX_AGE: u32 = 4; // ticks
+
+/// Space left for the payload after the header has been added.
+pub const MAX_PAYLOAD_SIZE: usize = MESSAGE_SIZE - HEADER_SIZE;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Count of messages that failed authentication.
+///
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Rejections {
+    /// Messages whose MAC didn't match their contents.
+    pub forged: u32,
+
+    /// Messages with a valid MAC that we've already received.
+    pub replayed: u32,
+
+    /// Messages with a valid MAC that were sent too long ago, or claim to be from the future.
+    pub stale: u32,
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Signs outgoing radio messages and verifies incoming ones.
+///
+/// Every message is prefixed with the sender's ID, the tick it was sent on and a MAC over the
+/// whole padded message. Only the low 16 bits of the tick are sent but the MAC covers all of it,
+/// so a message recorded in an earlier epoch won't verify. Messages are rejected if the MAC
+/// doesn't match, if they weren't sent within the last few ticks, or if that tick has already
+/// been seen from that sender. Only one message per tick gets through the radio anyway, so the
+/// tick doubles as a sequence number.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Authenticator {
+    key: [u64; 2],
+    sender: u16,
+
+    windows: BTreeMap<u16, ReplayWindow>,
+    rejections: Rejections,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Authenticator {
+    pub fn new(sender: u16) -> Self {
+        return Self::with_key(sender, TEAM_SECRET);
+    }
+
+    pub fn with_key(sender: u16, key: [u64; 2]) -> Self {
+        return Self {
+            key,
+            sender,
+
+            windows: BTreeMap::new(),
+            rejections: Rejections::default(),
+        };
+    }
+
+    pub fn rejections(&self) -> &Rejections {
+        return &self.rejections;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Authenticator {
+    /// Description
+    /// -----------
+    /// Add the authentication header to a payload. Payloads longer than MAX_PAYLOAD_SIZE are
+    /// truncated.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `tick` - Tick the message is sent on.
+    /// * `payload` - Message to send.
+    ///
+    pub fn seal(&self, tick: u32, payload: &[u8]) -> [u8; MESSAGE_SIZE] {
+        let mut message = [0; MESSAGE_SIZE];
+        message[0..2].copy_from_slice(&self.sender.to_le_bytes());
+        message[2..4].copy_from_slice(&(tick as u16).to_le_bytes());
+
+        let length = payload.len().min(MAX_PAYLOAD_SIZE);
+        message[HEADER_SIZE..HEADER_SIZE + length].copy_from_slice(&payload[..length]);
+
+        let mac = self.mac(&message, tick);
+        message[4..8].copy_from_slice(&mac.to_le_bytes());
+
+        return message;
+    }
+
+    /// Description
+    /// -----------
+    /// Verify a message and strip the authentication header from it.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `tick` - Tick the message is received on.
+    /// * `bytes` - Message received.
+    ///
+    /// Returns
+    /// -------
+    /// The sender's ID and the payload.
+    ///
+    pub fn open(
+        &mut self,
+        tick: u32,
+        bytes: &[u8],
+    ) -> Result<(u16, [u8; MAX_PAYLOAD_SIZE]), Error> {
+        if bytes.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let mut message = [0; MESSAGE_SIZE];
+        let length = bytes.len().min(MESSAGE_SIZE);
+        message[..length].copy_from_slice(&bytes[..length]);
+
+        let sender = u16::from_le_bytes([message[0], message[1]]);
+        let sent = u16::from_le_bytes([message[2], message[3]]);
+        let mac = u32::from_le_bytes([message[4], message[5], message[6], message[7]]);
+
+        // Take the tick closest to now with the same low bits. If the message is from another
+        // epoch the MAC won't match.
+        let age = (tick as u16).wrapping_sub(sent) as i16;
+        let sent = tick.wrapping_sub(age as i32 as u32);
+
+        if mac != self.mac(&message, sent) {
+            self.rejections.forged += 1;
+            return Err(Error::Forged);
+        }
+
+        if !(0..=MAX_AGE as i16).contains(&age) {
+            self.rejections.stale += 1;
+            return Err(Error::Stale);
+        }
+
+        // Only fresh messages get this far, so whoever we hear from first can't have been
+        // replaying something old.
+        let window = self
+            .windows
+            .entry(sender)
+            .or_insert(ReplayWindow::new(sent as u16));
+        if !window.accept(sent as u16) {
+            self.rejections.replayed += 1;
+            return Err(Error::Replayed);
+        }
+
+        let mut payload = [0; MAX_PAYLOAD_SIZE];
+        payload.copy_from_slice(&message[HEADER_SIZE..]);
+        return Ok((sender, payload));
+    }
+
+    /// Description
+    /// -----------
+    /// Calculate the MAC of a message, skipping over the bytes the MAC itself is stored in and
+    /// covering the whole of the tick it was sent on.
+    ///
+    fn mac(&self, message: &[u8; MESSAGE_SIZE], tick: u32) -> u32 {
+        let mut data = [0; MESSAGE_SIZE - 2];
+        data[..2].copy_from_slice(&message[..2]);
+        data[2..6].copy_from_slice(&tick.to_le_bytes());
+        data[6..].copy_from_slice(&message[HEADER_SIZE..]);
+
+        let hash = siphash24(self.key, &data);
+        return (hash ^ (hash >> 32)) as u32;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Tracks which of the most recent sequence numbers from a sender have been seen so that
+/// messages arriving out of order are still accepted, but only once.
+///
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(super) struct ReplayWindow {
+    latest: u16,
+    seen: u64,
+}
+
+impl ReplayWindow {
+    pub(super) fn new(first: u16) -> Self {
+        // Start just behind the first sequence number so that it's accepted.
+        return Self {
+            latest: first.wrapping_sub(1),
+            seen: 0,
+        };
+    }
+
+    pub(super) fn accept(&mut self, sequence: u16) -> bool {
+        let ahead = sequence.wrapping_sub(self.latest) as i16;
+
+        if ahead > 0 {
+            self.seen = self.seen.checked_shl(ahead as u32).unwrap_or(0) | 1;
+            self.latest = sequence;
+            return true;
+        }
+
+        let behind = -(ahead as i32) as u32;
+        if behind >= u64::BITS {
+            return false;
+        }
+
+        let bit = 1 << behind;
+        if self.seen & bit != 0 {
+            return false;
+        }
+
+        self.seen |= bit;
+        return true;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// SipHash-2-4 keyed hash.
+/// https://www.aumasson.jp/siphash/siphash.pdf
+///
+fn siphash24(key: [u64; 2], data: &[u8]) -> u64 {
+    let mut v = [
+        key[0] ^ 0x736f_6d65_7073_6575,
+        key[1] ^ 0x646f_7261_6e64_6f6d,
+        key[0] ^ 0x6c79_6765_6e65_7261,
+        key[1] ^ 0x7465_6462_7974_6573,
+    ];
+
+    fn round(v: &mut [u64; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    }
+
+    let chunks = data.chunks_exact(8);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        // Can't fail as chunks are always 8 bytes long.
+        let m = u64::from_le_bytes(chunk.try_into().unwrap());
+        v[3] ^= m;
+        round(&mut v);
+        round(&mut v);
+        v[0] ^= m;
+    }
+
+    let mut last = [0; 8];
+    last[..remainder.len()].copy_from_slice(remainder);
+    let m = u64::from_le_bytes(last) | ((data.len() as u64 & 0xFF) << 56);
+
+    v[3] ^= m;
+    round(&mut v);
+    round(&mut v);
+    v[0] ^= m;
+
+    v[2] ^= 0xFF;
+    for _ in 0..4 {
+        round(&mut v);
+    }
+
+    return v[0] ^ v[1] ^ v[2] ^ v[3];
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_siphash() {
+        // Test vector from the reference implementation.
+        let key = [0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908];
+        let data: Vec<u8> = (0..15).collect();
+
+        assert_eq!(siphash24(key, &data), 0xa129_ca61_49be_45e5);
+    }
+
+    #[test]
+    fn test_open_sealed() {
+        let sender = Authenticator::new(3);
+        let mut receiver = Authenticator::new(4);
+
+        let message = sender.seal(10, &[1, 2, 3]);
+        let (id, payload) = receiver.open(11, &message).unwrap();
+
+        assert_eq!(id, 3);
+        assert_eq!(payload[..4], [1, 2, 3, 0]);
+        assert_eq!(receiver.rejections(), &Rejections::default());
+    }
+
+    #[test]
+    fn test_forged_rejected() {
+        let sender = Authenticator::new(3);
+        let mut receiver = Authenticator::new(4);
+
+        // Any change to the message invalidates it.
+        for i in 0..MESSAGE_SIZE {
+            let mut message = sender.seal(10, &[1, 2, 3]);
+            message[i] ^= 0x01;
+            assert_eq!(receiver.open(11, &message), Err(Error::Forged));
+        }
+
+        // As does signing with a different key.
+        let enemy = Authenticator::with_key(3, [0, 0]);
+        assert_eq!(
+            receiver.open(11, &enemy.seal(10, &[1, 2, 3])),
+            Err(Error::Forged)
+        );
+
+        // Junk that happens to look like one of our messages.
+        assert_eq!(receiver.open(11, &[1; 17]), Err(Error::Forged));
+
+        assert_eq!(receiver.rejections().forged, MESSAGE_SIZE as u32 + 2);
+        assert_eq!(receiver.rejections().replayed, 0);
+    }
+
+    #[test]
+    fn test_replay_rejected() {
+        let sender = Authenticator::new(3);
+        let mut receiver = Authenticator::new(4);
+
+        let first = sender.seal(10, &[1]);
+        let second = sender.seal(11, &[2]);
+        let third = sender.seal(12, &[3]);
+
+        // Out of order messages are accepted once.
+        assert!(receiver.open(12, &second).is_ok());
+        assert!(receiver.open(12, &first).is_ok());
+        assert!(receiver.open(13, &third).is_ok());
+
+        assert_eq!(receiver.open(13, &first), Err(Error::Replayed));
+        assert_eq!(receiver.open(13, &second), Err(Error::Replayed));
+        assert_eq!(receiver.open(13, &third), Err(Error::Replayed));
+
+        assert_eq!(receiver.rejections().replayed, 3);
+    }
+
+    #[test]
+    fn test_stale_rejected() {
+        let sender = Authenticator::new(3);
+        let message = sender.seal(10, &[1]);
+
+        // A receiver that's only just spawned hasn't heard from the sender before, but still
+        // won't take an old message.
+        let mut receiver = Authenticator::new(4);
+        assert_eq!(receiver.open(10 + MAX_AGE + 1, &message), Err(Error::Stale));
+        assert_eq!(receiver.open(9, &message), Err(Error::Stale));
+        assert_eq!(receiver.rejections().stale, 2);
+
+        // Replaying it once the tick's low bits come round again doesn't work either.
+        let epoch = 1 << u16::BITS;
+        assert_eq!(receiver.open(10 + epoch + 1, &message), Err(Error::Forged));
+
+        assert!(receiver.open(10 + MAX_AGE, &message).is_ok());
+    }
+
+    #[test]
+    fn test_replay_window() {
+        let mut window = ReplayWindow::new(u16::MAX - 1);
+
+        // Sequence numbers wrap.
+        assert!(window.accept(u16::MAX - 1));
+        assert!(window.accept(u16::MAX));
+        assert!(window.accept(0));
+        assert!(window.accept(1));
+        assert!(!window.accept(u16::MAX));
+
+        // Messages too old to remember are rejected.
+        assert!(window.accept(100));
+        assert!(window.accept(100 - 63));
+        assert!(!window.accept(100 - 64));
+    }
+}
+
+////////////////////////////////////////////////////////////////
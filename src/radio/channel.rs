@@ -0,0 +1,391 @@
+use std::collections::VecDeque;
+
+////////////////////////////////////////////////////////////////
+
+/// Number of radio channels available.
+pub const CHANNELS: usize = 10;
+
+/// Channel every ship listens on when it spawns, before it can work out any other channel. Used
+/// to hand a target over to newly launched missiles.
+pub const LAUNCH_CHANNEL: usize = 0;
+
+/// Channel shared by the whole fleet. Ships take turns to transmit on it.
+pub const FLEET_CHANNEL: usize = 1;
+
+/// First of the channels used by missile salvos. Every channel from here up is a salvo channel.
+const FIRST_SALVO_CHANNEL: usize = 2;
+
+/// Number of slots in the fleet channel's schedule.
+const FLEET_SLOTS: u32 = 8;
+
+/// Ticks until a salvo's missiles will have run out of fuel or hit something.
+pub const SALVO_LIFETIME: u32 = 1800;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return the channel used by a missile salvo.
+///
+/// Only the launching ship derives this, from it's own ID and the tick it fired on. The channel
+/// is passed to the missiles in the handoff on the launch channel, sent the tick they're fired.
+///
+/// Parmaters
+/// ---------
+/// * `launcher` - ID of the ship that fired the salvo.
+/// * `launch_tick` - Tick the salvo was fired on.
+///
+fn salvo_channel(launcher: u16, launch_tick: u32) -> usize {
+    // Mix the bits so that salvos fired by neighbouring ships, or by the same ship in quick
+    // succession, land on different channels.
+    let key = ((launcher as u64) << 32) | launch_tick as u64;
+    let hash = key.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    let hash = hash ^ (hash >> 29);
+
+    return FIRST_SALVO_CHANNEL + (hash % (CHANNELS - FIRST_SALVO_CHANNEL) as u64) as usize;
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Time-division schedule for a shared channel. Time is split into repeating frames of `slots`
+/// ticks and each ship may only transmit in it's own slot, so that ships sharing the channel
+/// don't drown each other out.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Schedule {
+    slots: u32,
+    slot: u32,
+}
+
+impl Schedule {
+    pub fn new(slots: u32, slot: u32) -> Self {
+        let slots = slots.max(1);
+        return Self {
+            slots,
+            slot: slot % slots,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return the fleet channel schedule for a ship. Ships whose IDs differ by a multiple of the
+    /// frame length share a slot.
+    ///
+    pub fn for_ship(id: u16) -> Self {
+        return Self::new(FLEET_SLOTS, id as u32);
+    }
+
+    pub fn is_turn(&self, tick: u32) -> bool {
+        return tick % self.slots == self.slot;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// What the radio should be doing during a tick.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tuning {
+    /// Hand the target over to the salvo just launched, telling it the channel to listen on.
+    Launch(usize),
+
+    /// Update a salvo still in flight.
+    Salvo(usize),
+
+    /// Listen to the fleet channel, transmitting only if it's our turn.
+    Fleet { transmit: bool },
+}
+
+impl Tuning {
+    pub fn channel(&self) -> usize {
+        return match self {
+            Self::Launch(_) => LAUNCH_CHANNEL,
+            Self::Salvo(channel) => *channel,
+            Self::Fleet { .. } => FLEET_CHANNEL,
+        };
+    }
+
+    pub fn transmit(&self) -> bool {
+        return match self {
+            Self::Launch(_) | Self::Salvo(_) => true,
+            Self::Fleet { transmit } => *transmit,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Salvo {
+    channel: usize,
+    launch_tick: u32,
+}
+
+/// Description
+/// -----------
+/// Decides which channel a ship's radio is tuned to each tick.
+///
+/// A ship with a single radio can't listen to the fleet and guide it's missiles at once, so the
+/// radio is shared in time. Even ticks are spent on the fleet channel and odd ticks cycle through
+/// the salvos still in flight. Launches always take priority as the handoff only happens once.
+///
+/// Ships only transmit to the fleet on even ticks, whether or not they have salvos in flight, so
+/// that every slot in the fleet schedule still comes round for ships that do.
+///
+/// Changing channel only takes effect on the next tick, so each tick's tuning is planned on the
+/// tick before and the radio retuned then. For the same reason a launch has to be requested the
+/// tick before firing, so that the radio is on the launch channel when the missiles leave.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChannelManager {
+    id: u16,
+    schedule: Schedule,
+    salvos: VecDeque<Salvo>,
+
+    /// Tuning planned for the next tick, along with the tick.
+    planned: Option<(u32, Tuning)>,
+
+    /// Tick a launch has been requested for.
+    launch: Option<u32>,
+}
+
+impl ChannelManager {
+    pub fn new(id: u16) -> Self {
+        return Self {
+            id,
+            schedule: Schedule::for_ship(id),
+            salvos: VecDeque::new(),
+
+            planned: None,
+            launch: None,
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Ask for the radio to be on the launch channel next tick, ready to fire then. Must be
+    /// called before the tick is planned.
+    ///
+    pub fn request_launch(&mut self, tick: u32) {
+        self.launch = Some(tick + 1);
+    }
+
+    /// Description
+    /// -----------
+    /// Return whether the radio is on the launch channel this tick, so missiles can be fired.
+    ///
+    pub fn launching(&self, tick: u32) -> bool {
+        return matches!(self.tune(tick), Tuning::Launch(_));
+    }
+
+    /// Description
+    /// -----------
+    /// Record that a salvo was fired. Should only be called while launching so that the salvo's
+    /// missiles are handed it's channel.
+    ///
+    /// Returns
+    /// -------
+    /// The channel the salvo's missiles will listen on.
+    ///
+    pub fn launch_salvo(&mut self, tick: u32) -> usize {
+        if let Some(salvo) = self.salvos.back().filter(|s| s.launch_tick == tick) {
+            return salvo.channel;
+        }
+
+        let channel = salvo_channel(self.id, tick);
+        self.salvos.push_back(Salvo {
+            channel,
+            launch_tick: tick,
+        });
+
+        return channel;
+    }
+
+    /// Description
+    /// -----------
+    /// Return what the radio is doing this tick, as planned on the tick before.
+    ///
+    /// Returns
+    /// -------
+    /// A tuning that doesn't transmit if nothing was planned, as the channel the radio's on
+    /// isn't known.
+    ///
+    pub fn tune(&self, tick: u32) -> Tuning {
+        return match self.planned {
+            Some((planned, tuning)) if planned == tick => tuning,
+            _ => Tuning::Fleet { transmit: false },
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Decide what the radio will do next tick. Should be called once per tick, after any
+    /// salvos have been launched, and the radio tuned to the returned channel.
+    ///
+    pub fn plan(&mut self, tick: u32) -> Tuning {
+        let next = tick + 1;
+        let tuning = self.tuning_for(next);
+        self.planned = Some((next, tuning));
+        return tuning;
+    }
+
+    /// Description
+    /// -----------
+    /// Return what the radio should do on a tick.
+    ///
+    fn tuning_for(&mut self, tick: u32) -> Tuning {
+        while self
+            .salvos
+            .front()
+            .is_some_and(|s| tick.saturating_sub(s.launch_tick) > SALVO_LIFETIME)
+        {
+            self.salvos.pop_front();
+        }
+
+        // The handoff has to go out on the tick the salvo is fired.
+        if self.launch == Some(tick) {
+            return Tuning::Launch(salvo_channel(self.id, tick));
+        }
+
+        if tick % 2 == 1 && !self.salvos.is_empty() {
+            let index = (tick / 2) as usize % self.salvos.len();
+            return Tuning::Salvo(self.salvos[index].channel);
+        }
+
+        return Tuning::Fleet {
+            transmit: tick.is_multiple_of(2) && self.schedule.is_turn(tick / 2),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn test_salvo_channel() {
+        let mut used = BTreeSet::new();
+
+        for launcher in 0..20 {
+            for tick in 0..100 {
+                let channel = salvo_channel(launcher, tick);
+                assert_eq!(channel, salvo_channel(launcher, tick));
+                assert!((FIRST_SALVO_CHANNEL..CHANNELS).contains(&channel));
+
+                used.insert(channel);
+            }
+        }
+
+        // Every salvo channel gets used.
+        assert_eq!(used.len(), CHANNELS - FIRST_SALVO_CHANNEL);
+    }
+
+    #[test]
+    fn test_schedule() {
+        let schedules: Vec<Schedule> = (0..FLEET_SLOTS as u16).map(Schedule::for_ship).collect();
+
+        // Exactly one ship transmits each tick.
+        for tick in 0..100 {
+            let turns = schedules.iter().filter(|s| s.is_turn(tick)).count();
+            assert_eq!(turns, 1);
+        }
+
+        let schedule = Schedule::new(4, 6);
+        assert!(schedule.is_turn(2));
+        assert!(!schedule.is_turn(3));
+        assert!(schedule.is_turn(6));
+    }
+
+    #[test]
+    fn test_tuning_for() {
+        let mut channels = ChannelManager::new(3);
+
+        assert_eq!(channels.tuning_for(0), Tuning::Fleet { transmit: false });
+        assert_eq!(channels.tuning_for(3), Tuning::Fleet { transmit: false });
+        assert_eq!(channels.tuning_for(6), Tuning::Fleet { transmit: true });
+
+        channels.request_launch(9);
+        assert_eq!(
+            channels.tuning_for(10),
+            Tuning::Launch(salvo_channel(3, 10))
+        );
+
+        let first = channels.launch_salvo(10);
+        assert_eq!(channels.launch_salvo(10), first);
+        assert_eq!(first, salvo_channel(3, 10));
+        assert_eq!(channels.tuning_for(11), Tuning::Salvo(first));
+        assert_eq!(channels.tuning_for(13), Tuning::Salvo(first));
+        assert_eq!(channels.tuning_for(14), Tuning::Fleet { transmit: false });
+
+        // Odd ticks alternate between salvos in flight.
+        let second = channels.launch_salvo(20);
+        assert_eq!(channels.tuning_for(23), Tuning::Salvo(second));
+        assert_eq!(channels.tuning_for(25), Tuning::Salvo(first));
+
+        // Once a salvo is finished it's channel is no longer used.
+        assert_eq!(
+            channels.tuning_for(SALVO_LIFETIME + 15),
+            Tuning::Salvo(second)
+        );
+        assert_eq!(
+            channels.tuning_for(SALVO_LIFETIME + 25),
+            Tuning::Fleet { transmit: false }
+        );
+    }
+
+    #[test]
+    fn test_fleet_turns() {
+        // Every ship gets it's turn on the fleet channel even with salvos in flight.
+        for id in 0..FLEET_SLOTS as u16 {
+            let mut channels = ChannelManager::new(id);
+            channels.launch_salvo(0);
+
+            let turns = (2..(2 + 2 * FLEET_SLOTS))
+                .filter(|tick| channels.tuning_for(*tick) == Tuning::Fleet { transmit: true })
+                .count();
+            assert_eq!(turns, 1);
+        }
+    }
+
+    #[test]
+    fn test_tune() {
+        let mut channels = ChannelManager::new(3);
+
+        // Nothing's sent before the first plan as the radio's channel isn't known.
+        assert_eq!(channels.tune(0), Tuning::Fleet { transmit: false });
+
+        let mut channel = LAUNCH_CHANNEL;
+        for tick in 0..100 {
+            if tick == 40 || tick == 61 {
+                assert!(!channels.launching(tick));
+                channels.request_launch(tick);
+            }
+
+            // Salvos are fired once the radio is on the launch channel, and handed their channel.
+            let tuning = channels.tune(tick);
+            if tick == 41 || tick == 62 {
+                assert!(channels.launching(tick));
+                assert_eq!(tuning, Tuning::Launch(channels.launch_salvo(tick)));
+            }
+
+            // Every transmission goes out on the channel the radio was set to the tick before.
+            if tick > 0 && tuning.transmit() {
+                assert_eq!(tuning.channel(), channel);
+            }
+
+            let next = channels.plan(tick);
+            assert_eq!(channels.tune(tick + 1), next);
+            channel = next.channel();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,347 @@
+use oort_api::prelude::*;
+
+use super::error::Error;
+
+////////////////////////////////////////////////////////////////
+
+/// Half the width of the largest world we expect to play in. Positions outside of this are
+/// clamped to the edge.
+const WORLD_EXTENT: f64 = 50_000.0; // m
+
+/// Largest speed we expect any ship to reach. Velocities faster than this are clamped.
+const MAX_SPEED: f64 = 4_000.0; // m/s
+
+/// Quantisation used for each axis of a position.
+pub const POSITION: Quantiser = Quantiser::new(-WORLD_EXTENT, WORLD_EXTENT, 18);
+
+/// Quantisation used for each axis of a velocity.
+pub const VELOCITY: Quantiser = Quantiser::new(-MAX_SPEED, MAX_SPEED, 15);
+
+/// Largest position error worth reporting. Anything less accurate is of little use to other ships.
+const MAX_ERROR: f64 = 5_000.0; // m
+
+/// Quantisation used for the radius of a contact's position error.
+pub const ERROR: Quantiser = Quantiser::new(0.0, MAX_ERROR, 10);
+
+/// Largest cost a ship can bid for a target. Targets costing more than this are bid on at this
+/// cost.
+const MAX_COST: f64 = 100.0; // s
+
+/// Quantisation used for the cost of a target auction bid.
+pub const COST: Quantiser = Quantiser::new(0.0, MAX_COST, 12);
+
+/// Quantisation used for headings and bearings.
+pub const ANGLE: Quantiser = Quantiser::new(-PI, PI, 8);
+
+/// Number of bits used for timestamps. Timestamps are sent as a tick count which covers a little
+/// over 4 hours.
+const TIME_BITS: u32 = 20;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Maps a range of real values onto the integers representable in a fixed number of bits.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quantiser {
+    min: f64,
+    max: f64,
+    bits: u32,
+}
+
+impl Quantiser {
+    pub const fn new(min: f64, max: f64, bits: u32) -> Self {
+        return Self { min, max, bits };
+    }
+
+    /// Description
+    /// -----------
+    /// Return the difference between two adjacent quantised values.
+    /// A value within the range is always recovered to within half of this.
+    ///
+    pub fn step(&self) -> f64 {
+        return (self.max - self.min) / self.levels() as f64;
+    }
+
+    pub fn quantise(&self, value: f64) -> u64 {
+        if value.is_nan() {
+            return 0;
+        }
+
+        let value = value.clamp(self.min, self.max);
+        return ((value - self.min) / self.step()).round() as u64;
+    }
+
+    pub fn dequantise(&self, value: u64) -> f64 {
+        let value = value.min(self.levels());
+        return self.min + (value as f64 * self.step());
+    }
+
+    fn levels(&self) -> u64 {
+        return (1 << self.bits) - 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Trait for types that can be packed into a radio message.
+///
+pub trait Codec: Sized {
+    fn encode(&self, writer: &mut BitWriter);
+    fn decode(reader: &mut BitReader) -> Result<Self, Error>;
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Codec for Class {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_bits(*self as u64, 3);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        // All 8 values are valid classes so this can't fail.
+        return Ok(Class::from_f64(reader.read_bits(3)? as f64));
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Writes values into a byte buffer, most significant bit first.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        return self.bytes;
+    }
+
+    /// Description
+    /// -----------
+    /// Write the lowest `bits` bits of value.
+    ///
+    pub fn write_bits(&mut self, value: u64, bits: u32) {
+        for i in (0..bits).rev() {
+            if self.bits.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+
+            let bit = ((value >> i) & 1) as u8;
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= bit << (7 - (self.bits % 8));
+            self.bits += 1;
+        }
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_bits(value as u64, 1);
+    }
+
+    pub fn write_u16(&mut self, value: u16) {
+        self.write_bits(value as u64, 16);
+    }
+
+    pub fn write_quantised(&mut self, value: f64, quantiser: &Quantiser) {
+        self.write_bits(quantiser.quantise(value), quantiser.bits);
+    }
+
+    pub fn write_position(&mut self, position: Vec2) {
+        self.write_quantised(position.x, &POSITION);
+        self.write_quantised(position.y, &POSITION);
+    }
+
+    pub fn write_velocity(&mut self, velocity: Vec2) {
+        self.write_quantised(velocity.x, &VELOCITY);
+        self.write_quantised(velocity.y, &VELOCITY);
+    }
+
+    pub fn write_error(&mut self, error: f64) {
+        self.write_quantised(error, &ERROR);
+    }
+
+    pub fn write_cost(&mut self, cost: f64) {
+        self.write_quantised(cost, &COST);
+    }
+
+    pub fn write_angle(&mut self, angle: f64) {
+        // Wrap into -PI..PI first so that angles like 3PI/2 aren't clamped.
+        self.write_quantised(angle_diff(0.0, angle), &ANGLE);
+    }
+
+    pub fn write_time(&mut self, time: f64) {
+        let ticks = (time / TICK_LENGTH).round().max(0.0) as u64;
+        self.write_bits(ticks.min((1 << TIME_BITS) - 1), TIME_BITS);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Reads values written by a BitWriter.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    bits: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        return Self { bytes, bits: 0 };
+    }
+
+    pub fn read_bits(&mut self, bits: u32) -> Result<u64, Error> {
+        if self.bits + bits as usize > self.bytes.len() * 8 {
+            return Err(Error::Truncated);
+        }
+
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes[self.bits / 8];
+            let bit = (byte >> (7 - (self.bits % 8))) & 1;
+            value = (value << 1) | bit as u64;
+            self.bits += 1;
+        }
+
+        return Ok(value);
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, Error> {
+        return Ok(self.read_bits(1)? == 1);
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        return Ok(self.read_bits(16)? as u16);
+    }
+
+    pub fn read_quantised(&mut self, quantiser: &Quantiser) -> Result<f64, Error> {
+        return Ok(quantiser.dequantise(self.read_bits(quantiser.bits)?));
+    }
+
+    pub fn read_position(&mut self) -> Result<Vec2, Error> {
+        let x = self.read_quantised(&POSITION)?;
+        let y = self.read_quantised(&POSITION)?;
+        return Ok(vec2(x, y));
+    }
+
+    pub fn read_velocity(&mut self) -> Result<Vec2, Error> {
+        let x = self.read_quantised(&VELOCITY)?;
+        let y = self.read_quantised(&VELOCITY)?;
+        return Ok(vec2(x, y));
+    }
+
+    pub fn read_error(&mut self) -> Result<f64, Error> {
+        return self.read_quantised(&ERROR);
+    }
+
+    pub fn read_cost(&mut self) -> Result<f64, Error> {
+        return self.read_quantised(&COST);
+    }
+
+    pub fn read_angle(&mut self) -> Result<f64, Error> {
+        return self.read_quantised(&ANGLE);
+    }
+
+    pub fn read_time(&mut self) -> Result<f64, Error> {
+        return Ok(self.read_bits(TIME_BITS)? as f64 * TICK_LENGTH);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits_round_trip() {
+        let mut writer = BitWriter::new();
+        writer.write_bits(0b101, 3);
+        writer.write_bool(true);
+        writer.write_u16(0xBEEF);
+        writer.write_bits(0x1FFFF, 17);
+
+        let bytes = writer.into_bytes();
+        assert_eq!(bytes.len(), 5);
+
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(reader.read_bits(3), Ok(0b101));
+        assert_eq!(reader.read_bool(), Ok(true));
+        assert_eq!(reader.read_u16(), Ok(0xBEEF));
+        assert_eq!(reader.read_bits(17), Ok(0x1FFFF));
+
+        // Only padding is left.
+        assert_eq!(reader.read_bits(3), Ok(0));
+        assert_eq!(reader.read_bits(1), Err(Error::Truncated));
+    }
+
+    #[test]
+    fn test_quantisation_error() {
+        for quantiser in [POSITION, VELOCITY, ERROR, COST, ANGLE] {
+            let max_error = quantiser.step() / 2.0 + 1e-9;
+
+            for i in 0..=1000 {
+                let value = quantiser.min + (quantiser.max - quantiser.min) * (i as f64 / 1000.0);
+                let recovered = quantiser.dequantise(quantiser.quantise(value));
+                assert!(
+                    (recovered - value).abs() <= max_error,
+                    "{value} -> {recovered}"
+                );
+            }
+
+            // Values outside the range are clamped.
+            assert_eq!(
+                quantiser.dequantise(quantiser.quantise(f64::MAX)),
+                quantiser.max
+            );
+            assert_eq!(
+                quantiser.dequantise(quantiser.quantise(f64::MIN)),
+                quantiser.min
+            );
+        }
+
+        // Resolution needed for the values to be useful to other ships.
+        assert!(POSITION.step() < 0.5);
+        assert!(VELOCITY.step() < 0.25);
+        assert!(ERROR.step() < 5.0);
+        assert!(COST.step() < 0.025);
+        assert!(ANGLE.step() < 0.025);
+    }
+
+    #[test]
+    fn test_angle_wraps() {
+        let mut writer = BitWriter::new();
+        writer.write_angle(1.5 * PI);
+
+        let bytes = writer.into_bytes();
+        let angle = BitReader::new(&bytes).read_angle().unwrap();
+        assert!(angle_diff(angle, -0.5 * PI).abs() <= ANGLE.step());
+    }
+
+    #[test]
+    fn test_time() {
+        let mut writer = BitWriter::new();
+        writer.write_time(123.45);
+        writer.write_time(-1.0);
+
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert!((reader.read_time().unwrap() - 123.45).abs() <= TICK_LENGTH / 2.0);
+        assert_eq!(reader.read_time(), Ok(0.0));
+    }
+}
+
+////////////////////////////////////////////////////////////////
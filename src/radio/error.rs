@@ -0,0 +1,34 @@
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Reasons a received radio message could not be authenticated or decoded.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub enum Error {
+    /// The message contained no bytes.
+    Empty,
+
+    /// The message was encoded with a different protocol version.
+    UnsupportedVersion(u8),
+
+    /// The message kind is not one we know how to decode.
+    UnknownKind(u8),
+
+    /// The message ended before all of it's fields could be read.
+    Truncated,
+
+    /// A field contained a value outside of it's valid range.
+    InvalidField(&'static str),
+
+    /// The message wasn't signed with our team's key.
+    Forged,
+
+    /// The message has already been received.
+    Replayed,
+
+    /// The message wasn't sent recently enough to be trusted.
+    Stale,
+}
+
+////////////////////////////////////////////////////////////////
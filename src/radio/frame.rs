@@ -0,0 +1,114 @@
+use super::{
+    codec::{BitReader, BitWriter, Codec},
+    error::Error,
+    message::RadioMessage,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Destination of frames meant for every ship on the channel.
+pub const BROADCAST: u16 = u16::MAX;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A message along with the addressing needed to deliver it.
+///
+/// The sequence number is only meaningful for reliable frames. It stays the same across
+/// retransmissions so that the receiver can discard duplicates.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Frame {
+    pub sequence: u16,
+    pub destination: u16,
+    pub reliable: bool,
+    pub message: RadioMessage,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Frame {
+    pub fn is_for(&self, id: u16) -> bool {
+        return self.destination == BROADCAST || self.destination == id;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Codec for Frame {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.sequence);
+        writer.write_u16(self.destination);
+        writer.write_bool(self.reliable);
+        self.message.encode(writer);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            sequence: reader.read_u16()?,
+            destination: reader.read_u16()?,
+            reliable: reader.read_bool()?,
+            message: RadioMessage::decode(reader)?,
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use oort_api::prelude::{vec2, Class};
+
+    use super::super::{
+        auth::MAX_PAYLOAD_SIZE,
+        codec::{ERROR, POSITION, VELOCITY},
+        message::{Ack, ContactReport},
+    };
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let frame = Frame {
+            sequence: 1234,
+            destination: 5,
+            reliable: true,
+            message: RadioMessage::ContactReport(ContactReport {
+                reporter: 1,
+                contact: 2,
+                class: Class::Fighter,
+                // Use values that survive quantisation exactly.
+                position: vec2(POSITION.dequantise(1), POSITION.dequantise(2)),
+                velocity: vec2(VELOCITY.dequantise(3), VELOCITY.dequantise(4)),
+                error: ERROR.dequantise(5),
+                time: 0.0,
+            }),
+        };
+
+        let mut writer = BitWriter::new();
+        frame.encode(&mut writer);
+        let bytes = writer.into_bytes();
+
+        // The largest message must still fit once authenticated.
+        assert!(bytes.len() <= MAX_PAYLOAD_SIZE);
+        assert_eq!(Frame::decode(&mut BitReader::new(&bytes)), Ok(frame));
+    }
+
+    #[test]
+    fn test_is_for() {
+        let mut frame = Frame {
+            sequence: 0,
+            destination: BROADCAST,
+            reliable: false,
+            message: RadioMessage::Ack(Ack { sequence: 0 }),
+        };
+        assert!(frame.is_for(0));
+        assert!(frame.is_for(7));
+
+        frame.destination = 7;
+        assert!(!frame.is_for(0));
+        assert!(frame.is_for(7));
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -1,46 +1,761 @@
-use std::mem::MaybeUninit;
-
 use oort_api::prelude::*;
 
+use super::{
+    channel::CHANNELS,
+    codec::{BitReader, BitWriter, Codec, ERROR, POSITION},
+    error::Error,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Version of the radio protocol. Bump whenever the encoding of any message changes so that
+/// ships running older code reject messages rather than misinterpret them.
+pub const PROTOCOL_VERSION: u8 = 8;
+
+/// Wide enough for any version so that bumping it never wraps round to an older one.
+const VERSION_BITS: u32 = u8::BITS;
+const KIND_BITS: u32 = 5;
+
+////////////////////////////////////////////////////////////////
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum RadioMessage {
-    Position(Vec2),
-    Unknown,
+    ContactReport(ContactReport),
+    TargetAssignment(TargetAssignment),
+    Heartbeat(Heartbeat),
+    MissileDatalink(MissileDatalink),
+    EngagementStatus(EngagementStatus),
+    Ack(Ack),
+    Bid(Bid),
+    FleetOrder(FleetOrder),
 }
 
-impl RadioMessage {
-    pub fn to_bytes(&self) -> Vec<u8> {
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// A contact seen by the reporting ship's radar. The error is the radius around the position in
+/// which the reporter believes the contact was at the time of the report.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContactReport {
+    pub reporter: u16,
+    pub contact: u16,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub error: f64,
+    pub time: f64,
+}
+
+/// Description
+/// -----------
+/// Order for a ship to engage a target. Contact IDs are local to each ship's contact board so the
+/// target is identified by it's class and position.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct TargetAssignment {
+    pub assigner: u16,
+    pub assignee: u16,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+/// Description
+/// -----------
+/// Periodic broadcast of a ship's state, used to let the rest of the team know it's alive.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Heartbeat {
+    pub sender: u16,
+    pub class: Class,
+    pub role: Role,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub heading: f64,
+}
+
+/// Description
+/// -----------
+/// Target cue sent from a launching ship to it's missiles. The first is sent on the launch
+/// channel and tells the missiles which channel the rest will come on.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct MissileDatalink {
+    pub channel: usize,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub time: f64,
+}
+
+/// Description
+/// -----------
+/// What a ship is currently doing about a target.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct EngagementStatus {
+    pub sender: u16,
+    pub state: EngagementState,
+    pub class: Class,
+    pub position: Vec2,
+}
+
+/// Description
+/// -----------
+/// Acknowledges receipt of a reliable message. The sequence number is that of the reliable
+/// message's frame.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ack {
+    pub sequence: u16,
+}
+
+/// Description
+/// -----------
+/// A ship's claim on a target in the team's target auction. Lower costs win. As with target
+/// assignments the target is identified by it's class and position at the given time.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Bid {
+    pub bidder: u16,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub cost: f64,
+    pub time: f64,
+}
+
+/// Description
+/// -----------
+/// Standing order from the fleet's commander to every other ship in the fleet.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct FleetOrder {
+    pub commander: u16,
+    pub order: Order,
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Relative importance of messages, used to decide which to send first when more are waiting
+/// than the radio can transmit in one tick.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Unknown = 0,
+    Fighter = 1,
+    Duelist = 2,
+    Missile = 3,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Order {
+    /// Engage targets as allocated by the target auction.
+    Engage,
+
+    /// Form up on the commander.
+    FormUp(Formation),
+
+    /// Break off and fall back to a rally point.
+    Retreat(Vec2),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Formation {
+    LineAbreast = 0,
+    Trail = 1,
+    Wedge = 2,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EngagementState {
+    Searching = 0,
+    Tracking = 1,
+    Engaging = 2,
+    Evading = 3,
+    Disengaged = 4,
+}
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum MessageKind {
+    ContactReport = 1,
+    TargetAssignment = 2,
+    Heartbeat = 3,
+    MissileDatalink = 4,
+    EngagementStatus = 5,
+    Ack = 6,
+    Bid = 7,
+    FleetOrder = 8,
+}
+
+impl TryFrom<u8> for MessageKind {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        return match value {
+            1 => Ok(Self::ContactReport),
+            2 => Ok(Self::TargetAssignment),
+            3 => Ok(Self::Heartbeat),
+            4 => Ok(Self::MissileDatalink),
+            5 => Ok(Self::EngagementStatus),
+            6 => Ok(Self::Ack),
+            7 => Ok(Self::Bid),
+            8 => Ok(Self::FleetOrder),
+            _ => Err(Error::UnknownKind(value)),
+        };
+    }
+}
+
+impl Codec for Role {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_bits(*self as u64, 4);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return match reader.read_bits(4)? {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Fighter),
+            2 => Ok(Self::Duelist),
+            3 => Ok(Self::Missile),
+            _ => Err(Error::InvalidField("role")),
+        };
+    }
+}
+
+impl Codec for Order {
+    fn encode(&self, writer: &mut BitWriter) {
         match self {
-            Self::Position(position) => [&[1], &serialise_vec2(position)[..]].concat(),
-            Self::Unknown => Vec::new(),
+            Self::Engage => writer.write_bits(0, 2),
+            Self::FormUp(formation) => {
+                writer.write_bits(1, 2);
+                formation.encode(writer);
+            }
+            Self::Retreat(rally) => {
+                writer.write_bits(2, 2);
+                writer.write_position(*rally);
+            }
         }
     }
 
-    pub fn from_bytes(bytes: &[u8]) -> Self {
-        let type_byte = bytes[0];
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return match reader.read_bits(2)? {
+            0 => Ok(Self::Engage),
+            1 => Ok(Self::FormUp(Formation::decode(reader)?)),
+            2 => Ok(Self::Retreat(reader.read_position()?)),
+            _ => Err(Error::InvalidField("order")),
+        };
+    }
+}
+
+impl Codec for Formation {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_bits(*self as u64, 2);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return match reader.read_bits(2)? {
+            0 => Ok(Self::LineAbreast),
+            1 => Ok(Self::Trail),
+            2 => Ok(Self::Wedge),
+            _ => Err(Error::InvalidField("formation")),
+        };
+    }
+}
+
+impl Codec for EngagementState {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_bits(*self as u64, 3);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return match reader.read_bits(3)? {
+            0 => Ok(Self::Searching),
+            1 => Ok(Self::Tracking),
+            2 => Ok(Self::Engaging),
+            3 => Ok(Self::Evading),
+            4 => Ok(Self::Disengaged),
+            _ => Err(Error::InvalidField("engagement state")),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ContactReport {
+    /// Description
+    /// -----------
+    /// Return the radius around the reported position in which the contact was at the time of the
+    /// report, including the precision lost when the report was encoded.
+    ///
+    pub fn error_radius(&self) -> f64 {
+        return self.error
+            + (ERROR.step() / 2.0)
+            + (POSITION.step() * std::f64::consts::SQRT_2 / 2.0);
+    }
+}
+
+impl Codec for ContactReport {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.reporter);
+        writer.write_u16(self.contact);
+        self.class.encode(writer);
+        writer.write_position(self.position);
+        writer.write_velocity(self.velocity);
+        writer.write_error(self.error);
+        writer.write_time(self.time);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            reporter: reader.read_u16()?,
+            contact: reader.read_u16()?,
+            class: Class::decode(reader)?,
+            position: reader.read_position()?,
+            velocity: reader.read_velocity()?,
+            error: reader.read_error()?,
+            time: reader.read_time()?,
+        });
+    }
+}
+
+impl Codec for TargetAssignment {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.assigner);
+        writer.write_u16(self.assignee);
+        self.class.encode(writer);
+        writer.write_position(self.position);
+        writer.write_velocity(self.velocity);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            assigner: reader.read_u16()?,
+            assignee: reader.read_u16()?,
+            class: Class::decode(reader)?,
+            position: reader.read_position()?,
+            velocity: reader.read_velocity()?,
+        });
+    }
+}
+
+impl Codec for Heartbeat {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.sender);
+        self.class.encode(writer);
+        self.role.encode(writer);
+        writer.write_position(self.position);
+        writer.write_velocity(self.velocity);
+        writer.write_angle(self.heading);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            sender: reader.read_u16()?,
+            class: Class::decode(reader)?,
+            role: Role::decode(reader)?,
+            position: reader.read_position()?,
+            velocity: reader.read_velocity()?,
+            heading: reader.read_angle()?,
+        });
+    }
+}
 
-        match type_byte {
-            1 => Self::Position(deserialise_vec2(bytes[1..17].try_into().unwrap())),
-            _ => Self::Unknown,
+impl Codec for MissileDatalink {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_bits(self.channel as u64, 4);
+        self.class.encode(writer);
+        writer.write_position(self.position);
+        writer.write_velocity(self.velocity);
+        writer.write_time(self.time);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        let channel = reader.read_bits(4)? as usize;
+        if channel >= CHANNELS {
+            return Err(Error::InvalidField("channel"));
         }
+
+        return Ok(Self {
+            channel,
+            class: Class::decode(reader)?,
+            position: reader.read_position()?,
+            velocity: reader.read_velocity()?,
+            time: reader.read_time()?,
+        });
+    }
+}
+
+impl Codec for EngagementStatus {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.sender);
+        self.state.encode(writer);
+        self.class.encode(writer);
+        writer.write_position(self.position);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            sender: reader.read_u16()?,
+            state: EngagementState::decode(reader)?,
+            class: Class::decode(reader)?,
+            position: reader.read_position()?,
+        });
+    }
+}
+
+impl Codec for Ack {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.sequence);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            sequence: reader.read_u16()?,
+        });
+    }
+}
+
+impl Codec for Bid {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.bidder);
+        self.class.encode(writer);
+        writer.write_position(self.position);
+        writer.write_velocity(self.velocity);
+        writer.write_cost(self.cost);
+        writer.write_time(self.time);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            bidder: reader.read_u16()?,
+            class: Class::decode(reader)?,
+            position: reader.read_position()?,
+            velocity: reader.read_velocity()?,
+            cost: reader.read_cost()?,
+            time: reader.read_time()?,
+        });
+    }
+}
+
+impl Codec for FleetOrder {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_u16(self.commander);
+        self.order.encode(writer);
+    }
+
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        return Ok(Self {
+            commander: reader.read_u16()?,
+            order: Order::decode(reader)?,
+        });
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
-fn serialise_vec2(vec: &Vec2) -> [u8; 16] {
-    let mut bytes: [MaybeUninit<u8>; 16] = unsafe { MaybeUninit::uninit().assume_init() };
+impl RadioMessage {
+    fn kind(&self) -> MessageKind {
+        return match self {
+            Self::ContactReport(_) => MessageKind::ContactReport,
+            Self::TargetAssignment(_) => MessageKind::TargetAssignment,
+            Self::Heartbeat(_) => MessageKind::Heartbeat,
+            Self::MissileDatalink(_) => MessageKind::MissileDatalink,
+            Self::EngagementStatus(_) => MessageKind::EngagementStatus,
+            Self::Ack(_) => MessageKind::Ack,
+            Self::Bid(_) => MessageKind::Bid,
+            Self::FleetOrder(_) => MessageKind::FleetOrder,
+        };
+    }
+
+    pub fn priority(&self) -> Priority {
+        return match self {
+            Self::TargetAssignment(_) | Self::FleetOrder(_) => Priority::Critical,
+            Self::MissileDatalink(_) | Self::Ack(_) | Self::Bid(_) => Priority::High,
+            Self::ContactReport(_) | Self::EngagementStatus(_) => Priority::Normal,
+            Self::Heartbeat(_) => Priority::Low,
+        };
+    }
+}
+
+impl Codec for RadioMessage {
+    fn encode(&self, writer: &mut BitWriter) {
+        writer.write_bits(PROTOCOL_VERSION as u64, VERSION_BITS);
+        writer.write_bits(self.kind() as u64, KIND_BITS);
+
+        match self {
+            Self::ContactReport(report) => report.encode(writer),
+            Self::TargetAssignment(assignment) => assignment.encode(writer),
+            Self::Heartbeat(heartbeat) => heartbeat.encode(writer),
+            Self::MissileDatalink(link) => link.encode(writer),
+            Self::EngagementStatus(status) => status.encode(writer),
+            Self::Ack(ack) => ack.encode(writer),
+            Self::Bid(bid) => bid.encode(writer),
+            Self::FleetOrder(order) => order.encode(writer),
+        }
+    }
 
-    bytes[..8].copy_from_slice(&vec.x.to_le_bytes().map(MaybeUninit::new));
-    bytes[8..].copy_from_slice(&vec.y.to_le_bytes().map(MaybeUninit::new));
+    fn decode(reader: &mut BitReader) -> Result<Self, Error> {
+        let version = reader.read_bits(VERSION_BITS)? as u8;
+        if version != PROTOCOL_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
 
-    return unsafe { std::mem::transmute(bytes) };
+        return match MessageKind::try_from(reader.read_bits(KIND_BITS)? as u8)? {
+            MessageKind::ContactReport => ContactReport::decode(reader).map(Self::ContactReport),
+            MessageKind::TargetAssignment => {
+                TargetAssignment::decode(reader).map(Self::TargetAssignment)
+            }
+            MessageKind::Heartbeat => Heartbeat::decode(reader).map(Self::Heartbeat),
+            MessageKind::MissileDatalink => {
+                MissileDatalink::decode(reader).map(Self::MissileDatalink)
+            }
+            MessageKind::EngagementStatus => {
+                EngagementStatus::decode(reader).map(Self::EngagementStatus)
+            }
+            MessageKind::Ack => Ack::decode(reader).map(Self::Ack),
+            MessageKind::Bid => Bid::decode(reader).map(Self::Bid),
+            MessageKind::FleetOrder => FleetOrder::decode(reader).map(Self::FleetOrder),
+        };
+    }
 }
 
-fn deserialise_vec2(bytes: [u8; 16]) -> Vec2 {
-    let bytes: [[u8; 8]; 2] = unsafe { std::mem::transmute(bytes) };
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::super::codec::{ANGLE, COST, VELOCITY};
+    use super::*;
 
-    return vec2(f64::from_le_bytes(bytes[0]), f64::from_le_bytes(bytes[1]));
+    fn to_bytes(message: &RadioMessage) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        message.encode(&mut writer);
+        return writer.into_bytes();
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<RadioMessage, Error> {
+        return RadioMessage::decode(&mut BitReader::new(bytes));
+    }
+
+    fn assert_close(expected: f64, actual: f64, step: f64) {
+        assert!(
+            (expected - actual).abs() <= step / 2.0 + 1e-9,
+            "expected {expected} got {actual}"
+        );
+    }
+
+    fn assert_vec_close(expected: Vec2, actual: Vec2, step: f64) {
+        assert_close(expected.x, actual.x, step);
+        assert_close(expected.y, actual.y, step);
+    }
+
+    /// Description
+    /// -----------
+    /// Assert that a decoded message matches the one sent to within the quantisation error of
+    /// each field.
+    ///
+    fn assert_message_close(expected: &RadioMessage, actual: &RadioMessage) {
+        use RadioMessage::*;
+
+        match (expected, actual) {
+            (ContactReport(expected), ContactReport(actual)) => {
+                assert_eq!(expected.reporter, actual.reporter);
+                assert_eq!(expected.contact, actual.contact);
+                assert_eq!(expected.class, actual.class);
+                assert_vec_close(expected.position, actual.position, POSITION.step());
+                assert_vec_close(expected.velocity, actual.velocity, VELOCITY.step());
+                assert_close(expected.error, actual.error, ERROR.step());
+                assert_close(expected.time, actual.time, TICK_LENGTH);
+            }
+            (TargetAssignment(expected), TargetAssignment(actual)) => {
+                assert_eq!(expected.assigner, actual.assigner);
+                assert_eq!(expected.assignee, actual.assignee);
+                assert_eq!(expected.class, actual.class);
+                assert_vec_close(expected.position, actual.position, POSITION.step());
+                assert_vec_close(expected.velocity, actual.velocity, VELOCITY.step());
+            }
+            (Heartbeat(expected), Heartbeat(actual)) => {
+                assert_eq!(expected.sender, actual.sender);
+                assert_eq!(expected.class, actual.class);
+                assert_eq!(expected.role, actual.role);
+                assert_vec_close(expected.position, actual.position, POSITION.step());
+                assert_vec_close(expected.velocity, actual.velocity, VELOCITY.step());
+                assert_close(expected.heading, actual.heading, ANGLE.step());
+            }
+            (MissileDatalink(expected), MissileDatalink(actual)) => {
+                assert_eq!(expected.class, actual.class);
+                assert_vec_close(expected.position, actual.position, POSITION.step());
+                assert_vec_close(expected.velocity, actual.velocity, VELOCITY.step());
+                assert_close(expected.time, actual.time, TICK_LENGTH);
+            }
+            (EngagementStatus(expected), EngagementStatus(actual)) => {
+                assert_eq!(expected.sender, actual.sender);
+                assert_eq!(expected.state, actual.state);
+                assert_eq!(expected.class, actual.class);
+                assert_vec_close(expected.position, actual.position, POSITION.step());
+            }
+            (Ack(expected), Ack(actual)) => assert_eq!(expected, actual),
+            (Bid(expected), Bid(actual)) => {
+                assert_eq!(expected.bidder, actual.bidder);
+                assert_eq!(expected.class, actual.class);
+                assert_vec_close(expected.position, actual.position, POSITION.step());
+                assert_vec_close(expected.velocity, actual.velocity, VELOCITY.step());
+                assert_close(expected.cost, actual.cost, COST.step());
+                assert_close(expected.time, actual.time, TICK_LENGTH);
+            }
+            (FleetOrder(expected), FleetOrder(actual)) => {
+                assert_eq!(expected.commander, actual.commander);
+                match (expected.order, actual.order) {
+                    (Order::Retreat(expected), Order::Retreat(actual)) => {
+                        assert_vec_close(expected, actual, POSITION.step())
+                    }
+                    (expected, actual) => assert_eq!(expected, actual),
+                }
+            }
+            _ => panic!("expected {expected:?} got {actual:?}"),
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Test that every kind of message decodes to the message that was encoded.
+    ///
+    #[rstest]
+    #[case::contact_report(RadioMessage::ContactReport(ContactReport {
+        reporter: 3,
+        contact: 513,
+        class: Class::Frigate,
+        position: vec2(-1250.3, 9000.77),
+        velocity: vec2(12.51, -300.0),
+        error: 37.2,
+        time: 42.5,
+    }))]
+    #[case::target_assignment(RadioMessage::TargetAssignment(TargetAssignment {
+        assigner: 0,
+        assignee: 65535,
+        class: Class::Cruiser,
+        position: vec2(20000.0, -20000.0),
+        velocity: vec2(-1.0, 0.5),
+    }))]
+    #[case::heartbeat(RadioMessage::Heartbeat(Heartbeat {
+        sender: 7,
+        class: Class::Fighter,
+        role: Role::Duelist,
+        position: vec2(0.0, 100.0),
+        velocity: vec2(250.0, 0.0),
+        heading: -1.5,
+    }))]
+    #[case::missile_datalink(RadioMessage::MissileDatalink(MissileDatalink {
+        channel: 6,
+        class: Class::Missile,
+        position: vec2(4096.0, -8192.0),
+        velocity: vec2(0.0, -700.0),
+        time: 0.25,
+    }))]
+    #[case::engagement_status(RadioMessage::EngagementStatus(EngagementStatus {
+        sender: 12,
+        state: EngagementState::Evading,
+        class: Class::Torpedo,
+        position: vec2(-3.0, 3.0),
+    }))]
+    #[case::ack(RadioMessage::Ack(Ack { sequence: 40000 }))]
+    #[case::bid(RadioMessage::Bid(Bid {
+        bidder: 9,
+        class: Class::Fighter,
+        position: vec2(-700.0, 1234.5),
+        velocity: vec2(80.0, 80.0),
+        cost: 6.42,
+        time: 100.0,
+    }))]
+    #[case::engage(RadioMessage::FleetOrder(FleetOrder {
+        commander: 2,
+        order: Order::Engage,
+    }))]
+    #[case::form_up(RadioMessage::FleetOrder(FleetOrder {
+        commander: 2,
+        order: Order::FormUp(Formation::Wedge),
+    }))]
+    #[case::retreat(RadioMessage::FleetOrder(FleetOrder {
+        commander: 2,
+        order: Order::Retreat(vec2(-15000.0, 333.3)),
+    }))]
+    fn test_round_trip(#[case] message: RadioMessage) {
+        let bytes = to_bytes(&message);
+        let decoded = from_bytes(&bytes).unwrap();
+        assert_message_close(&message, &decoded);
+
+        // Re-encoding a decoded message should be lossless.
+        assert_eq!(to_bytes(&decoded), bytes);
+
+        // Messages are zero padded by the radio.
+        let mut padded = [0; 32];
+        padded[..bytes.len()].copy_from_slice(&bytes);
+        assert_eq!(from_bytes(&padded), Ok(decoded));
+    }
+
+    /// Description
+    /// -----------
+    /// Leave room in the 32 byte radio message for other layers to add their own headers.
+    ///
+    #[test]
+    fn test_contact_report_size() {
+        let report = RadioMessage::ContactReport(ContactReport {
+            reporter: 0,
+            contact: 0,
+            class: Class::Fighter,
+            position: vec2(0.0, 0.0),
+            velocity: vec2(0.0, 0.0),
+            error: 0.0,
+            time: 0.0,
+        });
+
+        assert!(to_bytes(&report).len() <= 18);
+    }
+
+    /// Description
+    /// -----------
+    /// Encode a message header followed by a payload given as `(value, bits)` pairs.
+    ///
+    fn raw(version: u8, kind: u8, payload: &[(u64, u32)]) -> Vec<u8> {
+        let mut writer = BitWriter::new();
+        writer.write_bits(version as u64, VERSION_BITS);
+        writer.write_bits(kind as u64, KIND_BITS);
+        for (value, bits) in payload {
+            writer.write_bits(*value, *bits);
+        }
+
+        return writer.into_bytes();
+    }
+
+    #[rstest]
+    #[case::empty(vec![], Error::Truncated)]
+    #[case::wrong_version(raw(PROTOCOL_VERSION + 1, 1, &[]), Error::UnsupportedVersion(PROTOCOL_VERSION + 1))]
+    #[case::old_version(raw(PROTOCOL_VERSION - 1, 1, &[]), Error::UnsupportedVersion(PROTOCOL_VERSION - 1))]
+    #[case::zeroed(vec![0; 32], Error::UnsupportedVersion(0))]
+    #[case::unknown_kind(raw(PROTOCOL_VERSION, 31, &[]), Error::UnknownKind(31))]
+    #[case::short_payload(raw(PROTOCOL_VERSION, 3, &[(7, 8)]), Error::Truncated)]
+    #[case::bad_order(raw(PROTOCOL_VERSION, 8, &[(0, 16), (3, 2)]), Error::InvalidField("order"))]
+    #[case::bad_role(raw(PROTOCOL_VERSION, 3, &[(0, 16), (0, 3), (15, 4)]), Error::InvalidField("role"))]
+    fn test_decode_error(#[case] bytes: Vec<u8>, #[case] error: Error) {
+        assert_eq!(from_bytes(&bytes), Err(error));
+    }
 }
 
 ////////////////////////////////////////////////////////////////
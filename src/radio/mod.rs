@@ -1,5 +1,18 @@
+mod auth;
+mod channel;
+mod codec;
+mod error;
+mod frame;
 mod message;
 mod ship_radio;
+mod transport;
 
-pub use message::RadioMessage;
+pub use channel::{ChannelManager, Tuning, SALVO_LIFETIME};
+pub use error::Error;
+pub use frame::BROADCAST;
+pub use message::{
+    Bid, ContactReport, FleetOrder, Formation, Heartbeat, MissileDatalink, Order, RadioMessage,
+    Role, TargetAssignment,
+};
 pub use ship_radio::Radio;
+pub use transport::Transport;
@@ -1,13 +1,22 @@
 use oort_api::prelude::*;
 
-use super::message::RadioMessage;
+use super::{
+    auth::{Authenticator, Rejections},
+    codec::{BitReader, BitWriter, Codec},
+    error::Error,
+    frame::Frame,
+};
 
 #[derive(Clone, PartialEq, Debug)]
-pub struct Radio {}
+pub struct Radio {
+    auth: Authenticator,
+}
 
 impl Radio {
     pub fn new() -> Self {
-        return Self {};
+        return Self {
+            auth: Authenticator::new(id() as u16),
+        };
     }
 
     pub fn set_channel(&self, channel: usize) {
@@ -18,11 +27,35 @@ impl Radio {
         return get_radio_channel();
     }
 
-    pub fn send(&self, message: RadioMessage) {
-        send_bytes(&message.to_bytes());
+    pub fn send_frame(&mut self, frame: &Frame) {
+        let mut writer = BitWriter::new();
+        frame.encode(&mut writer);
+
+        send_bytes(&self.auth.seal(current_tick(), &writer.into_bytes()));
+    }
+
+    /// Description
+    /// -----------
+    /// Receive a frame. Messages that weren't sent by our team, that weren't sent in the last few
+    /// ticks, or that we've already received, are rejected.
+    ///
+    /// Returns
+    /// -------
+    /// The ID of the sender and the frame.
+    ///
+    pub fn receive_frame(&mut self) -> Option<Result<(u16, Frame), Error>> {
+        return receive_bytes().map(|bytes| {
+            let (sender, payload) = self.auth.open(current_tick(), &bytes)?;
+            let frame = Frame::decode(&mut BitReader::new(&payload))?;
+            Ok((sender, frame))
+        });
     }
 
-    pub fn receive(&self) -> Option<RadioMessage> {
-        return receive_bytes().map(|bytes| RadioMessage::from_bytes(&bytes));
+    /// Description
+    /// -----------
+    /// Return the number of received messages rejected by authentication.
+    ///
+    pub fn rejections(&self) -> &Rejections {
+        return self.auth.rejections();
     }
 }
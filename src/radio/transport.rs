@@ -0,0 +1,573 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use oort_api::prelude::*;
+
+use crate::error;
+
+use super::{
+    auth::ReplayWindow,
+    channel::Tuning,
+    error::Error,
+    frame::Frame,
+    message::{Ack, Priority, RadioMessage},
+    ship_radio::Radio,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Number of messages the radio can transmit each tick.
+const MESSAGES_PER_TICK: usize = 1;
+
+/// Ticks to wait for an acknowledgement before sending a reliable message again.
+const RETRANSMIT_TICKS: u32 = 10;
+
+/// Number of times a reliable message is sent before giving up on it.
+const MAX_ATTEMPTS: u32 = 5;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Counters describing how well messages are getting through.
+///
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct TransportStats {
+    /// Reliable messages sent again after not being acknowledged in time.
+    pub retransmitted: u32,
+
+    /// Reliable messages that were never acknowledged.
+    pub failed: u32,
+
+    /// Unreliable messages that weren't sent because higher priority messages took their place.
+    pub expired: u32,
+
+    /// Received reliable messages that had already been delivered.
+    pub duplicates: u32,
+}
+
+////////////////////////////////////////////////////////////////
+
+#[derive(Clone, PartialEq, Debug)]
+struct Pending {
+    frame: Frame,
+    attempts: u32,
+    next_attempt: u32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Outbound {
+    priority: Priority,
+    frame: Frame,
+}
+
+impl Outbound {
+    fn is_ack(&self) -> bool {
+        return matches!(self.frame.message, RadioMessage::Ack(_));
+    }
+}
+
+/// A frame that could be sent this tick. Either the key of a pending reliable frame or the index
+/// of a queued frame.
+type Candidate = (Priority, Option<(u16, u16)>, usize);
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Message delivery on top of the radio.
+///
+/// The radio sends at most one message per tick and a receiver only hears the strongest message
+/// on it's channel, so messages are frequently lost. Reliable messages are resent until the
+/// destination acknowledges them. Acknowledgements wait for our next turn on the fleet channel.
+/// Everything else is queued for the current tick only and sent in order of priority, anything
+/// that doesn't fit is dropped since it'd be stale by the next tick.
+///
+/// Call receive() until it returns None, then flush() once per tick. The radio holds on to the
+/// last message it heard rather than handing over each message once, so it's only read once per
+/// tick.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct Transport {
+    radio: Radio,
+    id: u16,
+
+    sequence: u16,
+    queue: Vec<Outbound>,
+    pending: BTreeMap<(u16, u16), Pending>,
+    received: BTreeMap<u16, ReplayWindow>,
+
+    /// Tick the radio was last read on.
+    last_read: Option<u32>,
+
+    stats: TransportStats,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Transport {
+    pub fn new(radio: Radio) -> Self {
+        return Self::with_id(radio, id() as u16);
+    }
+
+    pub fn with_id(radio: Radio, id: u16) -> Self {
+        return Self {
+            radio,
+            id,
+
+            sequence: 0,
+            queue: Vec::new(),
+            pending: BTreeMap::new(),
+            received: BTreeMap::new(),
+
+            last_read: None,
+
+            stats: TransportStats::default(),
+        };
+    }
+
+    pub fn radio(&self) -> &Radio {
+        return &self.radio;
+    }
+
+    pub fn stats(&self) -> &TransportStats {
+        return &self.stats;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Transport {
+    /// Description
+    /// -----------
+    /// Queue a message to be sent this tick, if there's room. The destination is the ID of the
+    /// receiving ship or BROADCAST.
+    ///
+    pub fn send(&mut self, destination: u16, message: RadioMessage) {
+        self.queue.push(Outbound {
+            priority: message.priority(),
+            frame: Frame {
+                sequence: 0,
+                destination,
+                reliable: false,
+                message,
+            },
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Send a message to a single ship, repeating it until it's acknowledged.
+    ///
+    pub fn send_reliable(&mut self, destination: u16, message: RadioMessage) {
+        let sequence = self.sequence;
+        self.sequence = self.sequence.wrapping_add(1);
+
+        let frame = Frame {
+            sequence,
+            destination,
+            reliable: true,
+            message,
+        };
+
+        self.pending.insert(
+            (destination, sequence),
+            Pending {
+                frame,
+                attempts: 0,
+                next_attempt: 0,
+            },
+        );
+    }
+
+    /// Description
+    /// -----------
+    /// Receive the next message addressed to this ship.
+    ///
+    /// Returns
+    /// -------
+    /// The ID of the sender and the message.
+    ///
+    pub fn receive(&mut self) -> Option<(u16, RadioMessage)> {
+        return self.receive_from(current_tick(), Radio::receive_frame);
+    }
+
+    /// Description
+    /// -----------
+    /// Transmit the highest priority messages queued for this tick, if the tuning allows it, then
+    /// tune the radio for the next tick. Reliable messages are only sent on the fleet channel as
+    /// that's where the ships they're addressed to are listening.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `tuning` - What the radio is doing this tick. Must match the channel it was tuned to on
+    ///   the tick before.
+    /// * `next` - What the radio will be doing next tick.
+    ///
+    pub fn flush(&mut self, tuning: Tuning, next: Tuning) {
+        for frame in self.outbound(current_tick(), tuning) {
+            self.radio.send_frame(&frame);
+        }
+
+        self.radio.set_channel(next.channel());
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Transport {
+    /// Description
+    /// -----------
+    /// Read a frame from the radio, unless it's already been read this tick.
+    ///
+    fn receive_from<F>(&mut self, tick: u32, read: F) -> Option<(u16, RadioMessage)>
+    where
+        F: FnOnce(&mut Radio) -> Option<Result<(u16, Frame), Error>>,
+    {
+        if self.last_read == Some(tick) {
+            return None;
+        }
+        self.last_read = Some(tick);
+
+        return match read(&mut self.radio)? {
+            Ok((sender, frame)) => self.handle(sender, frame).map(|message| (sender, message)),
+            Err(error) => {
+                error::report(error);
+                None
+            }
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Process a received frame, acknowledging and deduplicating reliable messages.
+    ///
+    /// Returns
+    /// -------
+    /// The message if it's new and meant for this ship.
+    ///
+    fn handle(&mut self, sender: u16, frame: Frame) -> Option<RadioMessage> {
+        if !frame.is_for(self.id) {
+            return None;
+        }
+
+        if let RadioMessage::Ack(ack) = &frame.message {
+            self.pending.remove(&(sender, ack.sequence));
+            return None;
+        }
+
+        if !frame.reliable {
+            return Some(frame.message);
+        }
+
+        // Always acknowledge, our last acknowledgement may have been lost. No need to queue it
+        // twice if it hasn't gone out yet though.
+        let ack = RadioMessage::Ack(Ack {
+            sequence: frame.sequence,
+        });
+        let queued = self
+            .queue
+            .iter()
+            .any(|outbound| outbound.frame.destination == sender && outbound.frame.message == ack);
+        if !queued {
+            self.send(sender, ack);
+        }
+
+        let window = self
+            .received
+            .entry(sender)
+            .or_insert(ReplayWindow::new(frame.sequence));
+
+        if window.accept(frame.sequence) {
+            return Some(frame.message);
+        } else {
+            self.stats.duplicates += 1;
+            return None;
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Choose which frames to transmit this tick. Acknowledgements that weren't sent are kept for
+    /// the next turn on the fleet channel, the rest of the queue is dropped.
+    ///
+    fn outbound(&mut self, tick: u32, tuning: Tuning) -> Vec<Frame> {
+        let capacity = if tuning.transmit() {
+            MESSAGES_PER_TICK
+        } else {
+            0
+        };
+
+        // Give up on reliable messages that have run out of attempts.
+        let failed: Vec<(u16, u16)> = self
+            .pending
+            .iter()
+            .filter(|(_, p)| p.attempts >= MAX_ATTEMPTS && p.next_attempt <= tick)
+            .map(|(key, _)| *key)
+            .collect();
+
+        for key in failed {
+            self.pending.remove(&key);
+            self.stats.failed += 1;
+        }
+
+        // Acknowledgements and reliable messages are for ships listening on the fleet channel.
+        let fleet = matches!(tuning, Tuning::Fleet { .. });
+
+        // Candidates are this tick's queue plus any reliable messages that are due.
+        let mut candidates: Vec<Candidate> = self
+            .queue
+            .iter()
+            .enumerate()
+            .filter(|(_, outbound)| fleet || !outbound.is_ack())
+            .map(|(i, outbound)| (outbound.priority, None, i))
+            .collect();
+
+        candidates.extend(
+            self.pending
+                .iter()
+                .filter(|_| fleet)
+                .filter(|(_, p)| p.next_attempt <= tick)
+                .map(|(key, p)| (p.frame.message.priority(), Some(*key), 0)),
+        );
+
+        // Stable sort so that messages of the same priority go in the order they were sent.
+        candidates.sort_by_key(|candidate| Reverse(candidate.0));
+
+        let mut frames = Vec::new();
+        let mut sent = Vec::new();
+        for (_, key, index) in candidates.into_iter().take(capacity) {
+            if let Some(key) = key {
+                // Can't fail as the key was taken from the map.
+                let pending = self.pending.get_mut(&key).unwrap();
+
+                if pending.attempts > 0 {
+                    self.stats.retransmitted += 1;
+                }
+
+                pending.attempts += 1;
+                pending.next_attempt = tick + RETRANSMIT_TICKS;
+                frames.push(pending.frame.clone());
+            } else {
+                frames.push(self.queue[index].frame.clone());
+                sent.push(index);
+            }
+        }
+
+        let queue = std::mem::take(&mut self.queue);
+        for (index, outbound) in queue.into_iter().enumerate() {
+            if sent.contains(&index) {
+                continue;
+            }
+
+            if outbound.is_ack() {
+                self.queue.push(outbound);
+            } else {
+                self.stats.expired += 1;
+            }
+        }
+
+        return frames;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        frame::BROADCAST,
+        message::{EngagementState, EngagementStatus, Heartbeat, Role, TargetAssignment},
+    };
+    use super::*;
+
+    const TRANSMIT: Tuning = Tuning::Fleet { transmit: true };
+
+    fn transport(id: u16) -> Transport {
+        return Transport::with_id(Radio::new(), id);
+    }
+
+    fn assignment() -> RadioMessage {
+        return RadioMessage::TargetAssignment(TargetAssignment {
+            assigner: 1,
+            assignee: 2,
+            class: Class::Fighter,
+            position: vec2(100.0, 0.0),
+            velocity: vec2(0.0, 0.0),
+        });
+    }
+
+    fn heartbeat() -> RadioMessage {
+        return RadioMessage::Heartbeat(Heartbeat {
+            sender: 1,
+            class: Class::Fighter,
+            role: Role::Fighter,
+            position: vec2(0.0, 0.0),
+            velocity: vec2(0.0, 0.0),
+            heading: 0.0,
+        });
+    }
+
+    fn status() -> RadioMessage {
+        return RadioMessage::EngagementStatus(EngagementStatus {
+            sender: 1,
+            state: EngagementState::Searching,
+            class: Class::Fighter,
+            position: vec2(0.0, 0.0),
+        });
+    }
+
+    #[test]
+    fn test_reliable_delivery() {
+        let mut a = transport(1);
+        let mut b = transport(2);
+
+        a.send_reliable(2, assignment());
+
+        let frames = a.outbound(0, TRANSMIT);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(b.handle(1, frames[0].clone()), Some(assignment()));
+
+        // B acknowledges and A stops sending.
+        let acks = b.outbound(0, TRANSMIT);
+        assert!(matches!(acks[0].message, RadioMessage::Ack(_)));
+        assert_eq!(a.handle(2, acks[0].clone()), None);
+
+        assert!(a.outbound(RETRANSMIT_TICKS, TRANSMIT).is_empty());
+        assert_eq!(a.stats(), &TransportStats::default());
+    }
+
+    #[test]
+    fn test_retransmit() {
+        let mut a = transport(1);
+        a.send_reliable(2, assignment());
+
+        for attempt in 0..MAX_ATTEMPTS {
+            let tick = attempt * RETRANSMIT_TICKS;
+
+            assert_eq!(a.outbound(tick, TRANSMIT).len(), 1);
+            assert!(a.outbound(tick + 1, TRANSMIT).is_empty());
+        }
+
+        assert!(a
+            .outbound(MAX_ATTEMPTS * RETRANSMIT_TICKS, TRANSMIT)
+            .is_empty());
+        assert_eq!(a.stats().retransmitted, MAX_ATTEMPTS - 1);
+        assert_eq!(a.stats().failed, 1);
+    }
+
+    #[test]
+    fn test_duplicates_dropped() {
+        let mut a = transport(1);
+        let mut b = transport(2);
+
+        a.send_reliable(2, assignment());
+        let frame = a.outbound(0, TRANSMIT).pop().unwrap();
+
+        assert_eq!(b.handle(1, frame.clone()), Some(assignment()));
+        assert_eq!(b.outbound(0, TRANSMIT).len(), 1);
+
+        // The acknowledgement was lost so A sends again.
+        assert_eq!(b.handle(1, frame), None);
+        assert_eq!(b.outbound(1, TRANSMIT).len(), 1);
+
+        assert_eq!(b.stats().duplicates, 1);
+    }
+
+    #[test]
+    fn test_priority() {
+        let mut a = transport(1);
+
+        a.send(BROADCAST, heartbeat());
+        a.send(BROADCAST, status());
+        a.send_reliable(2, assignment());
+
+        let frames = a.outbound(0, TRANSMIT);
+        assert_eq!(frames.len(), MESSAGES_PER_TICK);
+        assert_eq!(frames[0].message, assignment());
+        assert_eq!(a.stats().expired, 2);
+
+        // Unreliable messages of the same priority go in the order they were queued.
+        a.send(BROADCAST, heartbeat());
+        a.send(BROADCAST, status());
+        a.send(BROADCAST, heartbeat());
+        assert_eq!(a.outbound(1, TRANSMIT)[0].message, status());
+    }
+
+    #[test]
+    fn test_tuning() {
+        let mut a = transport(1);
+
+        // Nothing is sent when it's not our turn.
+        a.send(BROADCAST, status());
+        a.send_reliable(2, assignment());
+        assert!(a.outbound(0, Tuning::Fleet { transmit: false }).is_empty());
+        assert_eq!(a.stats().expired, 1);
+
+        // Reliable messages wait for the fleet channel.
+        a.send(BROADCAST, heartbeat());
+        assert_eq!(a.outbound(1, Tuning::Salvo(5))[0].message, heartbeat());
+        assert_eq!(a.outbound(2, TRANSMIT)[0].message, assignment());
+    }
+
+    #[test]
+    fn test_ack_waits_for_turn() {
+        let mut a = transport(1);
+        let mut b = transport(2);
+
+        a.send_reliable(2, assignment());
+        let frame = a.outbound(0, TRANSMIT).pop().unwrap();
+        assert_eq!(b.handle(1, frame.clone()), Some(assignment()));
+
+        // Not our turn, or off guiding missiles, so the acknowledgement has to wait.
+        assert!(b.outbound(1, Tuning::Fleet { transmit: false }).is_empty());
+        assert!(b.outbound(2, Tuning::Salvo(5)).is_empty());
+
+        // A repeat of the message doesn't queue a second acknowledgement.
+        assert_eq!(b.handle(1, frame), None);
+
+        let acks = b.outbound(3, TRANSMIT);
+        assert!(matches!(acks[0].message, RadioMessage::Ack(_)));
+        assert_eq!(a.handle(2, acks[0].clone()), None);
+        assert!(b.outbound(4, TRANSMIT).is_empty());
+
+        assert!(a.outbound(RETRANSMIT_TICKS, TRANSMIT).is_empty());
+        assert_eq!(b.stats().expired, 0);
+    }
+
+    #[test]
+    fn test_receive_once_per_tick() {
+        let mut a = transport(1);
+        let mut b = transport(2);
+
+        a.send(BROADCAST, status());
+        let frame = a.outbound(0, TRANSMIT).pop().unwrap();
+
+        // The radio keeps handing back the same message for the rest of the tick.
+        let radio = |_: &mut Radio| Some(Ok((1, frame.clone())));
+
+        let mut received = Vec::new();
+        while let Some((_, message)) = b.receive_from(1, radio) {
+            received.push(message);
+        }
+        assert_eq!(received, vec![status()]);
+
+        assert_eq!(b.receive_from(2, radio), Some((1, status())));
+        assert_eq!(b.receive_from(2, radio), None);
+    }
+
+    #[test]
+    fn test_not_for_us() {
+        let mut a = transport(1);
+        let mut b = transport(2);
+
+        a.send(3, status());
+        let frame = a.outbound(0, TRANSMIT).pop().unwrap();
+        assert_eq!(b.handle(1, frame), None);
+
+        a.send(BROADCAST, status());
+        let frame = a.outbound(0, TRANSMIT).pop().unwrap();
+        assert_eq!(b.handle(1, frame), Some(status()));
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -5,6 +5,7 @@ use oort_api::prelude::scenario_name;
 pub enum Scenario {
     Sandbox,
     FighterDuel,
+    Furball,
 
     Unknown,
 }
@@ -20,6 +21,7 @@ impl Scenario {
         match scenario_name() {
             "sandbox" => Self::Sandbox,
             "fighter_duel" => Self::FighterDuel,
+            "furball" => Self::Furball,
             _ => Self::Unknown,
         }
     }
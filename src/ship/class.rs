@@ -10,7 +10,10 @@ pub trait ShipClassLoop {
 
 pub enum ShipClass {
     Fighter(Box<dyn ShipClassLoop>),
+    Frigate(Box<dyn ShipClassLoop>),
+    Cruiser(Box<dyn ShipClassLoop>),
     Missile(Box<dyn ShipClassLoop>),
+    Torpedo(Box<dyn ShipClassLoop>),
 
     ExContactDrawer(ContactDrawer),
     ExRadarTester(RadarTester),
@@ -0,0 +1,180 @@
+use oort_api::prelude::*;
+
+use super::radio::SALVO_LIFETIME;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Missiles fired together, guided as one over their own channel.
+///
+#[derive(Clone, PartialEq, Debug)]
+struct Salvo {
+    channel: usize,
+    launch_tick: u32,
+
+    /// Class of the missiles in the salvo.
+    class: Class,
+    missiles: u32,
+    target: usize,
+}
+
+/// Description
+/// -----------
+/// Keeps track of the missiles and torpedoes in flight and what each salvo is guided on to.
+///
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Battery {
+    salvos: Vec<Salvo>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Battery {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Description
+    /// -----------
+    /// Record a launch. Launches on the same channel in the same tick join the same salvo and
+    /// keep it's target.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `channel` - Channel the salvo listens on.
+    /// * `tick` - Tick the salvo was launched on.
+    /// * `class` - Class of missile launched.
+    /// * `target` - Contact to guide the missile on to.
+    ///
+    pub fn launch(&mut self, channel: usize, tick: u32, class: Class, target: usize) {
+        if let Some(salvo) = self
+            .salvos
+            .iter_mut()
+            .find(|s| s.channel == channel && s.launch_tick == tick)
+        {
+            salvo.missiles += 1;
+            return;
+        }
+
+        self.salvos.push(Salvo {
+            channel,
+            launch_tick: tick,
+            class,
+            missiles: 1,
+            target,
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Forget salvos that have run out of fuel.
+    ///
+    pub fn expire(&mut self, tick: u32) {
+        self.salvos
+            .retain(|s| tick.saturating_sub(s.launch_tick) <= SALVO_LIFETIME);
+    }
+
+    /// Description
+    /// -----------
+    /// Return the target of the salvo on a channel. If salvos have ended up sharing a channel,
+    /// the most recent one is guided.
+    ///
+    pub fn target(&self, channel: usize) -> Option<usize> {
+        return self
+            .salvos
+            .iter()
+            .rev()
+            .find(|s| s.channel == channel)
+            .map(|s| s.target);
+    }
+
+    /// Description
+    /// -----------
+    /// Return the number of missiles in flight towards a target.
+    ///
+    pub fn committed(&self, target: usize) -> u32 {
+        return self
+            .salvos
+            .iter()
+            .filter(|s| s.target == target)
+            .map(|s| s.missiles)
+            .sum();
+    }
+
+    /// Description
+    /// -----------
+    /// Point the salvos heading for targets that have gone at new ones.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `valid` - Whether a contact is still worth guiding a salvo on to.
+    /// * `choose` - Pick a new target for a salvo of the given class.
+    ///
+    pub fn retarget<V, C>(&mut self, valid: V, mut choose: C)
+    where
+        V: Fn(usize) -> bool,
+        C: FnMut(Class, &Self) -> Option<usize>,
+    {
+        for index in 0..self.salvos.len() {
+            if valid(self.salvos[index].target) {
+                continue;
+            }
+
+            if let Some(target) = choose(self.salvos[index].class, self) {
+                self.salvos[index].target = target;
+            }
+        }
+    }
+
+    pub fn in_flight(&self) -> u32 {
+        return self.salvos.iter().map(|s| s.missiles).sum();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launch() {
+        let mut battery = Battery::new();
+        battery.launch(4, 10, Class::Missile, 1);
+        battery.launch(4, 10, Class::Missile, 2);
+        battery.launch(6, 20, Class::Torpedo, 2);
+
+        // The second missile joined the first salvo and kept it's target.
+        assert_eq!(battery.target(4), Some(1));
+        assert_eq!(battery.target(6), Some(2));
+        assert_eq!(battery.committed(1), 2);
+        assert_eq!(battery.committed(2), 1);
+        assert_eq!(battery.in_flight(), 3);
+
+        battery.expire(10 + SALVO_LIFETIME + 1);
+        assert_eq!(battery.target(4), None);
+        assert_eq!(battery.in_flight(), 1);
+    }
+
+    #[test]
+    fn test_retarget() {
+        let mut battery = Battery::new();
+        battery.launch(4, 10, Class::Missile, 1);
+        battery.launch(6, 20, Class::Torpedo, 2);
+
+        // Target 1 has gone. Missiles go for 3, torpedoes would go for 4.
+        battery.retarget(
+            |target| target != 1,
+            |class, _| match class {
+                Class::Torpedo => Some(4),
+                _ => Some(3),
+            },
+        );
+
+        assert_eq!(battery.target(4), Some(3));
+        assert_eq!(battery.target(6), Some(2));
+    }
+}
+
+////////////////////////////////////////////////////////////////
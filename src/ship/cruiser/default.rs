@@ -0,0 +1,373 @@
+use std::iter;
+
+use oort_api::prelude::*;
+
+use super::{
+    battery::Battery,
+    class::ShipClassLoop,
+    control::{Rotation, Thrust, Translation},
+    defence::{PointDefence, Response},
+    draw, error,
+    math::kinematics::{
+        Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity,
+    },
+    radar::{
+        board::{ContactBoard, UniqueContactBoard},
+        CompositeRadar,
+    },
+    radio::{ChannelManager, MissileDatalink, Radio, RadioMessage, Transport, Tuning, BROADCAST},
+    stats::{MaxAcceleration, ShipStats, WeaponKind},
+    trigger::Trigger,
+    weapon::{Outcome, Turret, Weapon},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Most missiles to have in flight towards a fighter. Bigger ships can take more.
+const MAX_MISSILES_PER_FIGHTER: u32 = 2;
+const MAX_MISSILES_PER_SHIP: u32 = 6;
+
+/// Distance to keep from the closest enemy ship.
+const STANDOFF_RANGE: f64 = 8000.0; // m
+
+/// Lowest chance of a hit worth firing the flak for.
+const FLAK_HIT_PROBABILITY: f64 = 0.25;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Cruiser role. Keeps it's distance and fights with it's launchers, guiding each salvo on to
+/// it's own target, while the flak turret shoots down missiles coming the other way.
+///
+pub struct DefaultCruiser {
+    radar: CompositeRadar,
+    radio: Transport,
+    channels: ChannelManager,
+
+    /// Point defence turrets.
+    flak: Vec<Turret>,
+    launchers: Vec<Weapon>,
+
+    battery: Battery,
+    defence: PointDefence,
+    trigger: Trigger,
+
+    /// Contacts being tracked.
+    tracked: Vec<usize>,
+
+    acceleration: Vec2,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for DefaultCruiser {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for DefaultCruiser {
+    fn velocity(&self) -> Vec2 {
+        return velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for DefaultCruiser {
+    fn acceleration(&self) -> Vec2 {
+        return self.acceleration;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for DefaultCruiser {
+    fn heading(&self) -> f64 {
+        return heading();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for DefaultCruiser {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Translation for DefaultCruiser {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::CRUISER);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Rotation for DefaultCruiser {
+    fn set_angular_acceleration(&mut self, acceleration: f64) {
+        torque(acceleration)
+    }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::CRUISER.angular;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl DefaultCruiser {
+    pub fn new() -> Self {
+        let (flak, launchers): (Vec<Weapon>, Vec<Weapon>) =
+            Weapon::loadout(ShipStats::CRUISER.weapons).partition(Weapon::has_turret);
+        let flak_speed = flak.iter().map(Weapon::speed).fold(0.0, f64::max);
+        let flak = flak.into_iter().filter_map(Turret::new).collect();
+
+        return Self {
+            radar: CompositeRadar::new(UniqueContactBoard::new()),
+            radio: Transport::new(Radio::new()),
+            channels: ChannelManager::new(id() as u16),
+
+            flak,
+            launchers,
+
+            battery: Battery::new(),
+            defence: PointDefence::new(&ShipStats::CRUISER.dimensions, flak_speed),
+            trigger: Trigger::new(FLAK_HIT_PROBABILITY),
+
+            tracked: Vec::new(),
+
+            acceleration: vec2(0.0, 0.0),
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return the closest target worth launching at that doesn't already have enough missiles
+    /// heading for it.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `class` - Class of missile to be launched. Torpedoes are saved for big ships.
+    /// * `battery` - Missiles already in flight.
+    ///
+    fn choose_target(&self, class: Class, battery: &Battery) -> Option<usize> {
+        return self
+            .radar
+            .contacts
+            .iter()
+            .filter(|(id, contact)| {
+                let limit = match (class, contact.class()) {
+                    (Class::Missile, Class::Fighter) => MAX_MISSILES_PER_FIGHTER,
+                    (_, Class::Frigate | Class::Cruiser) => MAX_MISSILES_PER_SHIP,
+                    _ => return false,
+                };
+
+                battery.committed(**id) < limit
+            })
+            .min_by(|(_, a), (_, b)| a.distance_to(self).total_cmp(&b.distance_to(self)))
+            .map(|(id, _)| *id);
+    }
+
+    /// Description
+    /// -----------
+    /// Track the contacts being shot at or guided on to.
+    ///
+    fn update_tracking(&mut self, wanted: Vec<usize>) {
+        for id in self.tracked.iter().filter(|id| !wanted.contains(id)) {
+            self.radar.stop_tracking(*id);
+        }
+
+        for id in wanted.iter().filter(|id| !self.tracked.contains(id)) {
+            if let Err(error) = self.radar.start_tracking(*id) {
+                error::report(error);
+            }
+        }
+
+        self.tracked = wanted;
+    }
+
+    /// Description
+    /// -----------
+    /// Shoot at the incoming missile with the flak turrets, once they've come round far enough to
+    /// be likely to hit.
+    ///
+    fn point_defence(&mut self, id: usize) {
+        if let Some(solution) = self.defence.solution(self, &self.radar, id) {
+            let contact = self.radar.contacts.get(id);
+
+            for turret in &mut self.flak {
+                turret.aim(solution.aim_heading());
+
+                if contact.is_some_and(|contact| self.trigger.pull_on(&*turret, &solution, contact))
+                {
+                    turret.fire();
+                }
+            }
+
+            draw::aim_reticle(&solution);
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Fire the first loaded launcher that has something to fire at. Only one launcher is fired
+    /// each tick so each salvo can be given it's own target. Launchers are only fired once the
+    /// radio's ready to hand the target over.
+    ///
+    fn launch(&mut self) {
+        let tick = current_tick();
+
+        for launcher in &self.launchers {
+            let WeaponKind::Launcher(class) = launcher.kind() else {
+                continue;
+            };
+
+            let Some(target) = self.choose_target(class, &self.battery) else {
+                continue;
+            };
+
+            match Weapon::launch(iter::once(*launcher), &mut self.channels) {
+                Outcome::Fired => {
+                    let channel = self.channels.launch_salvo(tick);
+                    self.battery.launch(channel, tick, class, target);
+                    return;
+                }
+                Outcome::Waiting => return,
+                Outcome::Reloading(_) => (),
+            }
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Keep our distance from the closest enemy ship, turning to face it.
+    ///
+    fn manoeuvre(&mut self) {
+        let closest = self
+            .radar
+            .contacts
+            .iter()
+            .map(|(_, contact)| contact)
+            .filter(|c| matches!(c.class(), Class::Fighter | Class::Frigate | Class::Cruiser))
+            .min_by(|a, b| a.distance_to(self).total_cmp(&b.distance_to(self)));
+
+        let Some(contact) = closest else {
+            return;
+        };
+
+        // Back off astern if we're right on top of it, as there's no direction away from it.
+        let offset = self.position_relative_to(contact);
+        let direction = if offset.length() > 0.0 {
+            offset.normalize()
+        } else {
+            -vec2(1.0, 0.0).rotate(self.heading())
+        };
+        let station = KinematicModel::new(
+            contact.position() + (direction * STANDOFF_RANGE),
+            contact.velocity(),
+            vec2(0.0, 0.0),
+        );
+        let aim = contact.position();
+
+        let max_acceleration = ShipStats::CRUISER.acceleration;
+        let max_acceleration = f64::min(max_acceleration.lateral, max_acceleration.reverse);
+        self.hold_station(&station, max_acceleration, f64::INFINITY);
+        self.turn_to_face(&aim);
+    }
+
+    /// Description
+    /// -----------
+    /// Send a salvo it's target.
+    ///
+    fn guide(&mut self, channel: usize) {
+        let Some(target) = self
+            .battery
+            .target(channel)
+            .and_then(|id| self.radar.contacts.get(id))
+        else {
+            return;
+        };
+
+        self.radio.send(
+            BROADCAST,
+            RadioMessage::MissileDatalink(MissileDatalink {
+                channel,
+                class: target.class(),
+                position: target.position(),
+                velocity: target.velocity(),
+                time: target.time(),
+            }),
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for DefaultCruiser {
+    fn tick(&mut self) {
+        debug!("Role: Cruiser");
+
+        self.radar.scan(&self.position());
+
+        // Salvos whose target has gone go after something else.
+        let mut battery = std::mem::take(&mut self.battery);
+        battery.expire(current_tick());
+        battery.retarget(
+            |id| self.radar.contacts.get(id).is_some(),
+            |class, battery| self.choose_target(class, battery),
+        );
+        self.battery = battery;
+
+        // The flak turret can cover any missile, so it doesn't need to wait to be told to switch.
+        let vessel = KinematicModel::from(&*self);
+        self.defence.update(&vessel, &self.radar);
+        let incoming = self.defence.most_urgent().map(|threat| threat.id);
+        if let Some(id) = incoming {
+            self.point_defence(id);
+        }
+
+        self.launch();
+        self.manoeuvre();
+
+        let reload = self
+            .flak
+            .iter()
+            .map(|turret| turret.weapon().reload_time())
+            .fold(f64::INFINITY, f64::min);
+        if let Response::Evade(direction) = self.defence.decide(self, &self.radar, reload) {
+            self.set_acceleration(direction * ShipStats::CRUISER.acceleration.lateral);
+        }
+
+        let mut wanted: Vec<usize> = incoming.into_iter().collect();
+        for id in self.radar.contacts.iter().map(|(id, _)| *id) {
+            if self.battery.committed(id) > 0 && !wanted.contains(&id) {
+                wanted.push(id);
+            }
+        }
+        self.update_tracking(wanted);
+
+        self.radar.adjust(&KinematicModel::from(&*self));
+        self.radar.draw_contacts();
+        debug!("Missiles in flight: {}", self.battery.in_flight());
+
+        let tuning = self.channels.tune(current_tick());
+        match tuning {
+            Tuning::Launch(channel) | Tuning::Salvo(channel) => self.guide(channel),
+            Tuning::Fleet { .. } => (),
+        }
+
+        let next = self.channels.plan(current_tick());
+        self.radio.flush(tuning, next);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,8 @@
+mod battery;
+mod default;
+
+// Imports.
+use super::{class, control, defence, draw, error, math, radar, radio, stats, trigger, weapon};
+
+// Exports.
+pub use self::default::DefaultCruiser;
@@ -0,0 +1,143 @@
+use oort_api::prelude::*;
+
+use super::{
+    math::{
+        geometry::Ellipse,
+        kinematics::{Acceleration, KinematicModel, Position, Velocity},
+        FiringSolution,
+    },
+    stats::Dimensions,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Where each shot in the pattern goes across the uncertainty region, as a fraction of how far
+/// the target could be either side of the aim point. The centre comes up most often since it's
+/// where the target is most likely to be.
+const PATTERN: [f64; 8] = [0.0, -0.5, 0.5, 0.0, -0.25, 0.25, -0.75, 0.75];
+
+/// Half the gap between neighbouring points in the pattern, as a fraction of the same distance.
+/// Each shot only has to cover the slice of the region nearest it's own point.
+const CELL: f64 = 0.125;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Spreads successive shots across the region a target could be in at impact, so that a target
+/// we can't pin down to within it's own size still gets hit by some of them.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Dispersion {
+    /// Position in the pattern of the next shot.
+    shot: usize,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Dispersion {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Description
+    /// -----------
+    /// Return where to aim the next shot in the pattern, and the slice of the region it covers.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `shooter` - Ship doing the shooting.
+    /// * `solution` - Where the target is expected to be hit.
+    /// * `area` - Region the target could be in at the time of impact.
+    /// * `dimensions` - Dimensions of the target.
+    ///
+    /// Returns
+    /// -------
+    /// None if the region is narrow enough that shots at the solution will hit wherever the
+    /// target is in it.
+    ///
+    pub fn aim_point<T: Position>(
+        &self,
+        shooter: &T,
+        solution: &FiringSolution,
+        area: &Ellipse,
+        dimensions: &Dimensions,
+    ) -> Option<(KinematicModel, Ellipse)> {
+        let across = shooter.bearing_to(solution) + (PI / 2.0);
+
+        // Shots land within the target's size of the aim point so only the rest of the region
+        // needs covering.
+        let spread = area.extent(across) - dimensions.average_radius();
+        if spread <= 0.0 {
+            return None;
+        }
+
+        let offset = vec2(1.0, 0.0).rotate(across) * (PATTERN[self.shot] * spread);
+        let aim = KinematicModel::new(
+            solution.position() + offset,
+            solution.velocity(),
+            solution.acceleration(),
+        );
+
+        let cell = Ellipse::new(&aim.position(), across, 0.0, 2.0 * CELL * spread);
+        return Some((aim, cell));
+    }
+
+    /// Description
+    /// -----------
+    /// Move on to the next point in the pattern. Call once a shot has been fired.
+    ///
+    pub fn next(&mut self) {
+        self.shot = (self.shot + 1) % PATTERN.len();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aim_point() {
+        let shooter = KinematicModel::new(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+        let target = KinematicModel::new(vec2(1000.0, 0.0), vec2(0.0, 10.0), vec2(0.0, 1.0));
+        let solution = FiringSolution::new(&shooter, 1000.0, &target).unwrap();
+        let dimensions = Dimensions::FIGHTER;
+
+        // Region no wider than the target.
+        let narrow = Ellipse::new(&solution.position(), 0.0, 10.0, 10.0);
+        let dispersion = Dispersion::new();
+        assert!(dispersion
+            .aim_point(&shooter, &solution, &narrow, &dimensions)
+            .is_none());
+
+        // Spread out across the region, staying inside it.
+        let wide = Ellipse::new(&solution.position(), 0.0, 400.0, 10.0);
+        let mut dispersion = Dispersion::new();
+        let mut offsets = Vec::new();
+        let spread = 200.0 - dimensions.average_radius();
+        for _ in 0..PATTERN.len() {
+            let (aim, cell) = dispersion
+                .aim_point(&shooter, &solution, &wide, &dimensions)
+                .unwrap();
+            offsets.push(aim.position().y - solution.position().y);
+            dispersion.next();
+
+            // Each shot covers it's own slice of the region.
+            let across = shooter.bearing_to(&solution) + (PI / 2.0);
+            let exact = wide.extent(across) - dimensions.average_radius();
+            assert!((cell.extent(across) - (CELL * exact)).abs() < 1e-6);
+            assert!(cell.extent(across + (PI / 2.0)) < 1e-6);
+        }
+
+        assert!(offsets.iter().all(|offset| offset.abs() <= spread + 1e-6));
+        assert!(offsets.iter().any(|offset| *offset < -spread / 2.0));
+        assert!(offsets.iter().any(|offset| *offset > spread / 2.0));
+
+        // Back to the start of the pattern.
+        assert_eq!(dispersion, Dispersion::new());
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,66 @@
+use oort_api::prelude::*;
+
+use super::{
+    class::ShipClassLoop,
+    ecs::{
+        AdjustRadar, Behaviour, FireWeapons, Hunt, Navigate, Navigation, Radar, Radio,
+        ReceiveRadio, ScanRadar, Schedule, TransmitRadio, Weapons, World,
+    },
+    stats::{MaxAcceleration, ShipStats},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Fighter role built from components and systems. Hunts down the closest enemy fighter.
+///
+/// Only flown in the "intercept" sandbox mode. Shares it's radar and radio systems with the
+/// default fighter but hunts on it's own rather than fighting as part of the fleet.
+///
+pub struct Interceptor {
+    world: World,
+    schedule: Schedule,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Interceptor {
+    pub fn new() -> Self {
+        let mut world = World::new();
+
+        let ship = world.spawn();
+        world.radar.insert(ship, Radar::new());
+        world.radio.insert(ship, Radio::new());
+        world
+            .weapons
+            .insert(ship, Weapons::new(ShipStats::FIGHTER.weapons));
+        world
+            .navigation
+            .insert(ship, Navigation::new(MaxAcceleration::FIGHTER));
+        world.behaviour.insert(ship, Behaviour::new(Class::Fighter));
+
+        let schedule = Schedule::new(vec![
+            Box::new(ScanRadar),
+            Box::new(ReceiveRadio),
+            Box::new(Hunt),
+            Box::new(Navigate),
+            Box::new(FireWeapons),
+            Box::new(TransmitRadio),
+            Box::new(AdjustRadar),
+        ]);
+
+        return Self { world, schedule };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for Interceptor {
+    fn tick(&mut self) {
+        debug!("Role: Interceptor");
+        self.schedule.run(&mut self.world);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -1,8 +1,9 @@
 mod contact_draw;
+mod interceptor;
 mod radar_test;
 
 // Imports.
-use super::{control, draw, math, radar};
+use super::{class, control, draw, ecs, math, radar, stats};
 
 // Exports.
-pub use self::{contact_draw::ContactDrawer, radar_test::RadarTester};
+pub use self::{contact_draw::ContactDrawer, interceptor::Interceptor, radar_test::RadarTester};
@@ -3,7 +3,7 @@ use oort_api::prelude::*;
 use crate::radar::contacts::{SearchContact, TrackedContact};
 
 use super::{
-    control::{Rotation, Translation},
+    control::{Rotation, Thrust, Translation},
     draw::{self, Colour},
     math::kinematics::{
         Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity,
@@ -13,6 +13,7 @@ use super::{
         contacts::Contact,
         CompositeRadar,
     },
+    stats::MaxAcceleration,
 };
 
 ////////////////////////////////////////////////////////////////
@@ -68,9 +69,11 @@ impl AngularVelocity for RadarTester {
 ////////////////////////////////////////////////////////////////
 
 impl Translation for RadarTester {
-    fn set_acceleration(&mut self, acceleration: Vec2) {
-        accelerate(acceleration);
-        self.acceleration = acceleration;
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::FIGHTER);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
     }
 }
 
@@ -80,13 +83,15 @@ impl Rotation for RadarTester {
     fn set_angular_acceleration(&mut self, acceleration: f64) {
         torque(acceleration)
     }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::FIGHTER.angular;
+    }
 }
 
 ////////////////////////////////////////////////////////////////
 
 impl RadarTester {
-    const BULLET_SPEED: f64 = 1000.0; // m/s
-
     pub fn new() -> Self {
         debug!("spawn fighter team 0 position (50, 0) heading 0");
         debug!("spawn missile team 1 position (3000, 3000) heading 0");
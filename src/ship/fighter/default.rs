@@ -5,26 +5,62 @@ use crate::radar::contacts::{SearchContact, TrackedContact};
 use super::{
     class::ShipClassLoop,
     control::{Rotation, Translation},
+    defence::{PointDefence, Response},
+    dispersion::Dispersion,
     draw::{self, Colour, Trail},
+    ecs::{
+        AdjustRadar, Entity, Navigation, Radar, Radio, ReceiveRadio, ScanRadar, Schedule, System,
+        TransmitRadio, Weapons, World, WorldEvent,
+    },
+    fleet::{intercept_cost, Auction, Command, Election, FormationKeeper},
     math::{
-        kinematics::{Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity},
+        kinematics::{Acceleration, Heading, KinematicModel, Position, Velocity},
         FiringSolution,
     },
     radar::{
-        board::{ContactBoard, UniqueContactBoard},
-        contacts::Contact,
-        CompositeRadar,
+        board::ContactBoard,
+        contacts::{Contact, RadarContact},
+        TacticalPicture,
     },
-    radio::{Radio, RadioMessage},
+    radio::{Heartbeat, Order, RadioMessage, Role, Tuning, BROADCAST},
+    stats::{MaxAcceleration, ShipStats},
+    trigger::{impact_area, Trigger},
+    weapon::{Outcome, Weapon},
 };
 
 ////////////////////////////////////////////////////////////////
 
+/// Lowest chance of a hit worth firing the guns for.
+const HIT_PROBABILITY: f64 = 0.25;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Fighter role that fights as part of the fleet, built from components and systems. The radar
+/// and radio are run by the shared systems, with the fleet tactics run as a system in between.
+///
 pub struct DefaultFighter {
-    radar: CompositeRadar,
-    radio: Radio,
+    world: World,
+    schedule: Schedule,
+}
 
-    acceleration: Vec2,
+/// Description
+/// -----------
+/// The default fighter's own decisions: what to engage, when to follow orders and when to defend
+/// itself. Runs after the radio's been read and before it's written.
+///
+struct Tactics {
+    ship: Entity,
+
+    picture: TacticalPicture,
+    auction: Auction,
+    election: Election,
+    command: Command,
+    formation: FormationKeeper,
+    defence: PointDefence,
+    trigger: Trigger,
+    dispersion: Dispersion,
 
     target: Option<usize>,
 
@@ -34,188 +70,298 @@ pub struct DefaultFighter {
 
 ////////////////////////////////////////////////////////////////
 
-impl Position for DefaultFighter {
-    fn position(&self) -> Vec2 {
-        return position();
+impl DefaultFighter {
+    pub fn new() -> Self {
+        let mut world = World::new();
+
+        let ship = world.spawn();
+        world.radar.insert(ship, Radar::new());
+        world.radio.insert(ship, Radio::new());
+        world
+            .weapons
+            .insert(ship, Weapons::new(ShipStats::FIGHTER.weapons));
+        world
+            .navigation
+            .insert(ship, Navigation::new(MaxAcceleration::FIGHTER));
+
+        let schedule = Schedule::new(vec![
+            Box::new(ScanRadar),
+            Box::new(ReceiveRadio),
+            Box::new(Tactics::new(ship)),
+            Box::new(TransmitRadio),
+            Box::new(AdjustRadar),
+        ]);
+
+        return Self { world, schedule };
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl Velocity for DefaultFighter {
-    fn velocity(&self) -> Vec2 {
-        return velocity();
-    }
-}
+impl Tactics {
+    fn new(ship: Entity) -> Self {
+        return Self {
+            ship,
+
+            picture: TacticalPicture::new(id() as u16),
+            auction: Auction::new(id() as u16),
+            election: Election::new(id() as u16),
+            command: Command::new(id() as u16),
+            formation: FormationKeeper::new(),
+            defence: PointDefence::new(
+                &ShipStats::FIGHTER.dimensions,
+                ShipStats::FIGHTER.weapons[0].speed,
+            ),
+            trigger: Trigger::new(HIT_PROBABILITY),
+            dispersion: Dispersion::new(),
 
-////////////////////////////////////////////////////////////////
+            target: None,
 
-impl Acceleration for DefaultFighter {
-    fn acceleration(&self) -> Vec2 {
-        return self.acceleration;
+            target_trail_actual: Trail::with_length(256),
+            target_trail_aim: Trail::with_length(256),
+        };
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl Heading for DefaultFighter {
-    fn heading(&self) -> f64 {
-        return heading();
-    }
-}
+impl Tactics {
+    fn fire_guns(&self) -> bool {
+        let mut fired = false;
+        for gun in Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_gun) {
+            fired |= gun.fire() == Outcome::Fired;
+        }
 
-////////////////////////////////////////////////////////////////
+        return fired;
+    }
 
-impl AngularVelocity for DefaultFighter {
-    fn angular_velocity(&self) -> f64 {
-        return angular_velocity();
+    /// Description
+    /// -----------
+    /// Return the time until the first gun is ready to fire.
+    ///
+    fn gun_reload_time(&self) -> f64 {
+        return Weapon::loadout(ShipStats::FIGHTER.weapons)
+            .filter(Weapon::is_gun)
+            .map(|gun| gun.reload_time())
+            .fold(f64::INFINITY, f64::min);
     }
-}
 
-////////////////////////////////////////////////////////////////
+    /// Description
+    /// -----------
+    /// Return our cost to engage a contact, for bidding in the team's target auction.
+    ///
+    fn target_cost<C: Velocity>(&self, navigation: &Navigation, contact: &C) -> f64 {
+        let cost = intercept_cost(navigation, contact, ShipStats::FIGHTER.weapons[0].speed);
 
-impl Translation for DefaultFighter {
-    fn set_acceleration(&mut self, acceleration: Vec2) {
-        accelerate(acceleration);
-        self.acceleration = acceleration;
-    }
-}
+        // Time until the guns are ready again.
+        let cost = cost + self.gun_reload_time();
 
-////////////////////////////////////////////////////////////////
+        return cost;
+    }
 
-impl Rotation for DefaultFighter {
-    fn set_angular_acceleration(&mut self, acceleration: f64) {
-        torque(acceleration)
+    fn heartbeat(&self, navigation: &Navigation) -> Heartbeat {
+        return Heartbeat {
+            sender: self.election.id(),
+            class: Class::Fighter,
+            role: Role::Fighter,
+            position: navigation.position(),
+            velocity: navigation.velocity(),
+            heading: navigation.heading(),
+        };
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl DefaultFighter {
-    const BULLET_SPEED: f64 = 1000.0; // m/s
-
-    pub fn new() -> Self {
-        return Self {
-            radar: CompositeRadar::new(UniqueContactBoard::new()),
-            radio: Radio::new(),
-
-            acceleration: vec2(0.0, 0.0),
-
-            target: None,
+////////////////////////////////////////////////////////////////
 
-            target_trail_actual: Trail::with_length(256),
-            target_trail_aim: Trail::with_length(256),
+impl System for Tactics {
+    fn run(&mut self, world: &mut World) {
+        let ship = self.ship;
+        let (
+            Some(Radar { radar, track, .. }),
+            Some(Radio {
+                transport: radio,
+                channels,
+            }),
+            Some(weapons),
+            Some(navigation),
+        ) = (
+            world.radar.get_mut(ship),
+            world.radio.get_mut(ship),
+            world.weapons.get_mut(ship),
+            world.navigation.get_mut(ship),
+        )
+        else {
+            return;
         };
-    }
-}
-
-////////////////////////////////////////////////////////////////
 
-impl DefaultFighter {
-    fn fire_guns(&self) {
-        fire(0);
-    }
+        // Hear what the rest of the team can see, which targets they're bidding for and what the
+        // commander wants.
+        let messages = world.events.iter().filter_map(|event| match event {
+            WorldEvent::Message(entity, message) if *entity == ship => Some(message),
+            _ => None,
+        });
+
+        for message in messages {
+            match message {
+                RadioMessage::ContactReport(report) => {
+                    self.picture.fuse(radar, report);
+                }
+                RadioMessage::Bid(bid) => self.auction.receive(radar, bid),
+                RadioMessage::Heartbeat(heartbeat) => self.election.receive(heartbeat),
+                RadioMessage::FleetOrder(order) => self.command.receive_order(order),
+                RadioMessage::TargetAssignment(assignment) => {
+                    self.command.receive_assignment(assignment)
+                }
+                _ => (),
+            }
+        }
+        self.picture.expire(radar);
+        self.election.update();
 
-    fn launch_missile(&mut self) {
-        fire(1);
-        self.radio.set_channel(0);
+        let order = self.command.order(&self.election);
+        debug!("Commander: {}", self.election.leader());
+        debug!("Order: {order:?}");
+        debug!("Radio rejections: {:?}", radio.radio().rejections());
+        debug!("Radio stats: {:?}", radio.stats());
 
-        if let Some((_, target)) = self
-            .radar
+        // Agree with the rest of the team who engages what.
+        let costs: Vec<(usize, f64)> = radar
             .contacts
             .iter()
-            .find(|(_, c)| matches!(c, Contact::Tracked(_)))
-        {
-            self.radio.send(RadioMessage::Position(target.position()));
-        }
-    }
-}
+            .map(|(id, contact)| (*id, self.target_cost(navigation, contact)))
+            .collect();
+        self.auction.update(radar, costs);
+
+        // Start tracking the target we've been assigned. Orders from the commander come first.
+        let assigned = self
+            .command
+            .assigned_target(&self.election, radar)
+            .or(self.auction.assigned());
+
+        // Missiles about to hit us come before anything we've been assigned.
+        self.defence
+            .update(&KinematicModel::from(&*navigation), radar);
+        let reload = self.gun_reload_time();
+        let response = self.defence.decide(&*navigation, radar, reload);
+        debug!("Point defence: {response:?}");
+
+        let assigned = match response {
+            Response::Defend(id) => Some(id),
+            Response::Primary | Response::Evade(_) => assigned,
+        };
 
-////////////////////////////////////////////////////////////////
+        // The radar starts tracking it on the next scan and missiles are sent it once launched.
+        self.target = assigned;
+        *track = assigned;
+        weapons.target = assigned;
 
-impl ShipClassLoop for DefaultFighter {
-    fn tick(&mut self) {
-        debug!("Default");
+        let current_target = self.target.and_then(|id| radar.contacts.get(id));
 
-        // Update radar contacts.
-        self.radar.scan(&self.position());
+        // Fly in formation while formed up or breaking away from it.
+        let station =
+            self.formation
+                .update(&KinematicModel::from(&*navigation), order, &self.election);
+        debug!("Formation: {:?}", self.formation.phase());
 
-        // Find the current target.
-        let current_target = self
-            .target
-            .and_then(|id| Some(id).zip(self.radar.contacts.get(id)));
+        let rally = match order {
+            Order::Retreat(rally) => Some(rally),
+            Order::Engage | Order::FormUp(_) => None,
+        };
 
-        // Check that their's not a higher priority target.
-        let current_target = if let Some((target_id, target)) = current_target {
-            let other_contacts = self
-                .radar
-                .contacts
-                .iter()
-                .filter(|(_, c)| matches!(c, Contact::Search(_)));
+        // If we have a tracked target, get a firing solution.
+        let firing_solution = match current_target {
+            Some(_) if matches!(response, Response::Defend(_)) => self
+                .target
+                .and_then(|id| self.defence.solution(&*navigation, radar, id)),
 
-            let missiles = other_contacts.filter(|(_, c)| c.class() == Class::Missile);
+            // Following orders comes before engaging.
+            _ if station.is_some() || rally.is_some() => None,
 
-            let priority = missiles.min_by_key(|(_, c)| c.distance_to(self) as u32);
-            let priority = priority.filter(|(&id, _)| id != target_id);
-            let priority = priority.filter(|(_, m)| m.distance_to(self) < target.distance_to(self));
+            Some(Contact::Tracked(contact)) => {
+                self.target_trail_actual.update(contact);
+                // self.target_trail_actual.draw(Colour::Green);
 
-            if let Some((priority_id, _)) = priority {
-                self.target = Some(*priority_id);
-                if let Err(error) = self.radar.start_tracking(*priority_id) {
-                    debug!("ERROR - {error:?}")
-                }
+                debug!("Target velocity: {}", contact.velocity());
+                debug!("Target accel: {}", contact.acceleration());
 
-                None
-            } else {
-                Some(target)
-            }
-        } else {
-            // If the previous target has been lost start tracking a new one.
-            let new_target = self
-                .radar
-                .contacts
-                .iter()
-                .min_by_key(|(_, c)| c.distance_to(self) as u32);
-
-            if let Some((id, _)) = new_target {
-                self.target = Some(*id);
-                if let Err(error) = self.radar.start_tracking(*id) {
-                    debug!("ERROR - {error:?}")
-                }
+                FiringSolution::new(&*navigation, ShipStats::FIGHTER.weapons[0].speed, contact).ok()
             }
 
-            None
-        };
+            // Only a wingman can see the target. Engage it using their reports while our own
+            // radar looks for it.
+            Some(Contact::Remote(contact)) => {
+                debug!("Target reported by: {}", contact.reporter());
+
+                // Reports can be several seconds old, so aim from where it should be by now.
+                let now = KinematicModel::new(
+                    contact.position_after(contact.time_elapsed()),
+                    contact.velocity(),
+                    contact.acceleration(),
+                );
+                FiringSolution::new(&*navigation, ShipStats::FIGHTER.weapons[0].speed, &now).ok()
+            }
 
-        // If we have a tracked target, get a firing solution.
-        let firing_solution = if let Some(Contact::Tracked(contact)) = current_target {
-            self.target_trail_actual.update(contact);
-            // self.target_trail_actual.draw(Colour::Green);
+            _ => {
+                let map_centre = vec2(0.0, 0.0);
+                navigation.turn_to_face(&map_centre);
+                navigation.accelerate_towards(&map_centre);
 
-            debug!("Target velocity: {}", contact.velocity());
-            debug!("Target accel: {}", contact.acceleration());
+                None
+            }
+        };
 
-            FiringSolution::new(self, Self::BULLET_SPEED, contact)
-        } else {
-            let map_centre = vec2(0.0, 0.0);
-            self.turn_to_face(&map_centre);
-            self.accelerate_towards(&map_centre);
+        if let Some(station) = station {
+            let max_acceleration = MaxAcceleration::FIGHTER;
+            let max_acceleration = f64::min(max_acceleration.lateral, max_acceleration.reverse);
 
-            None
-        };
+            navigation.hold_station(&station, max_acceleration, station.max_closing_speed);
+            navigation.turn_to_heading(station.heading);
+        } else if let Some(rally) = rally {
+            navigation.turn_to_face(&rally);
+            navigation.accelerate_towards(&rally);
+        }
 
         // Engage the target using the firing solution.
         if let Some(solution) = firing_solution {
             debug!("Engaging target");
-            self.turn_to_track(&solution);
-            self.accelerate_towards(&solution);
 
-            if self.relative_bearing_to(&solution).abs() < (PI / 4.0) {
-                self.launch_missile();
+            // Spread shots over where the target could be if we can't pin it down.
+            let target = self.target.and_then(|id| radar.contacts.get(id));
+            let impact = target.and_then(|target| impact_area(&solution, target));
+            let pattern = impact.as_ref().and_then(|(area, dimensions)| {
+                self.dispersion
+                    .aim_point(&*navigation, &solution, area, dimensions)
+            });
+
+            let fire = match (&pattern, &impact) {
+                (Some((aim, cell)), Some((_, dimensions))) => {
+                    self.trigger.pull(&*navigation, aim, cell, dimensions)
+                }
+                (None, Some((area, dimensions))) => {
+                    self.trigger.pull(&*navigation, &solution, area, dimensions)
+                }
+                _ => false,
+            };
+
+            match &pattern {
+                Some((aim, _)) => {
+                    navigation.turn_to_track(aim);
+                    draw::aim_reticle(aim);
+                }
+                None => navigation.turn_to_track(&solution),
+            }
+            navigation.accelerate_towards(&solution);
+
+            if navigation.relative_bearing_to(&solution).abs() < (PI / 4.0) {
+                let launchers =
+                    Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_launcher);
+                Weapon::launch(launchers, channels);
             }
 
-            if self.relative_bearing_to(&solution).abs() < 0.02 {
-                self.fire_guns();
+            if fire && self.fire_guns() {
+                self.dispersion.next();
             }
 
             self.target_trail_aim.update(&solution);
@@ -223,9 +369,48 @@ impl ShipClassLoop for DefaultFighter {
             draw::aim_reticle(&solution);
         }
 
-        self.radar.adjust(&KinematicModel::from(&*self));
-        // draw::heading(self);
-        self.radar.draw_contacts();
+        if let Response::Evade(direction) = response {
+            navigation.set_acceleration(direction * MaxAcceleration::FIGHTER.lateral);
+        }
+
+        let tuning = channels.tune(current_tick());
+        let heartbeat = self.heartbeat(navigation);
+        if let Some(assignment) = self.command.take_assignment(&self.election, radar) {
+            radio.send_reliable(
+                assignment.assignee,
+                RadioMessage::TargetAssignment(assignment),
+            );
+        }
+
+        match tuning {
+            Tuning::Fleet { transmit: true } => {
+                // Orders, bids and heartbeats are only sent when they change or are due to be
+                // repeated. Report what we can see the rest of the time.
+                let order = self.command.take_order(&self.election, radar, &heartbeat);
+
+                if let Some(order) = order {
+                    radio.send(BROADCAST, RadioMessage::FleetOrder(order));
+                } else if let Some(bid) = self.auction.take_bid(radar) {
+                    radio.send(BROADCAST, RadioMessage::Bid(bid));
+                } else if self.election.take_heartbeat() {
+                    radio.send(BROADCAST, RadioMessage::Heartbeat(heartbeat));
+                } else if let Some(report) = self.picture.next_report(radar) {
+                    radio.send(BROADCAST, RadioMessage::ContactReport(report));
+                }
+            }
+
+            // Missiles in flight are sent our target by the radio system.
+            Tuning::Fleet { transmit: false } | Tuning::Launch(_) | Tuning::Salvo(_) => (),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for DefaultFighter {
+    fn tick(&mut self) {
+        debug!("Default");
+        self.schedule.run(&mut self.world);
     }
 }
 
@@ -1,38 +1,92 @@
 use oort_api::prelude::*;
 
-use crate::radar::contacts::{SearchContact, TrackedContact};
-
 use super::{
+    behaviour::{Event, Hierarchy, Machine, RadarEvents, StateMachine},
     class::ShipClassLoop,
-    control::{Rotation, Translation},
-    draw,
+    control::{Rotation, Thrust, Translation},
+    draw, error,
     math::{
         kinematics::{Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity},
         FiringSolution,
     },
     radar::{
         board::{ContactBoard, UniqueContactBoard},
-        contacts::Contact,
+        contacts::{Contact, RadarContact},
         CompositeRadar,
     },
-    radio::{Radio, RadioMessage},
+    radio::{ChannelManager, MissileDatalink, Radio, RadioMessage, Transport, Tuning, BROADCAST},
+    stats::{MaxAcceleration, ShipStats},
+    trigger::Trigger,
+    weapon::Weapon,
 };
 
 ////////////////////////////////////////////////////////////////
 
-pub struct Duelist {
+/// Time spent defending against a missile before going back to the fighter regardless.
+const DEFEND_TIMEOUT: u32 = 600; // ticks
+
+/// Lowest chance of a hit worth firing the guns for.
+const HIT_PROBABILITY: f64 = 0.25;
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Stages of a duel with a single enemy fighter.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Duel {
+    /// No enemy fighter on the board.
+    Search,
+
+    /// Fighting the enemy fighter. Only entered through one of the states nested in it.
+    Engage { fighter: usize },
+
+    /// Waiting for the tracking radar to pick up the fighter.
+    Track { fighter: usize },
+
+    /// Shooting at the fighter.
+    Attack { fighter: usize },
+
+    /// Shooting down a missile fired at us.
+    Defend { fighter: usize, missile: usize },
+}
+
+impl Hierarchy for Duel {
+    fn parent(self) -> Option<Self> {
+        return match self {
+            Duel::Search | Duel::Engage { .. } => None,
+            Duel::Track { fighter } | Duel::Attack { fighter } | Duel::Defend { fighter, .. } => {
+                Some(Duel::Engage { fighter })
+            }
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// The parts of the duelist controlled by it's state machine.
+///
+struct Hull {
     radar: CompositeRadar,
-    radio: Radio,
+    radio: Transport,
+    channels: ChannelManager,
+    trigger: Trigger,
 
     acceleration: Vec2,
+}
 
-    enemy_fighter: Option<usize>,
-    enemy_missile: Option<usize>,
+pub struct Duelist {
+    hull: Hull,
+    radar_events: RadarEvents,
+    states: StateMachine<Duel>,
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl Position for Duelist {
+impl Position for Hull {
     fn position(&self) -> Vec2 {
         return position();
     }
@@ -40,7 +94,7 @@ impl Position for Duelist {
 
 ////////////////////////////////////////////////////////////////
 
-impl Velocity for Duelist {
+impl Velocity for Hull {
     fn velocity(&self) -> Vec2 {
         return velocity();
     }
@@ -48,7 +102,7 @@ impl Velocity for Duelist {
 
 ////////////////////////////////////////////////////////////////
 
-impl Acceleration for Duelist {
+impl Acceleration for Hull {
     fn acceleration(&self) -> Vec2 {
         return self.acceleration;
     }
@@ -56,7 +110,7 @@ impl Acceleration for Duelist {
 
 ////////////////////////////////////////////////////////////////
 
-impl Heading for Duelist {
+impl Heading for Hull {
     fn heading(&self) -> f64 {
         return heading();
     }
@@ -64,7 +118,7 @@ impl Heading for Duelist {
 
 ////////////////////////////////////////////////////////////////
 
-impl AngularVelocity for Duelist {
+impl AngularVelocity for Hull {
     fn angular_velocity(&self) -> f64 {
         return angular_velocity();
     }
@@ -72,177 +126,195 @@ impl AngularVelocity for Duelist {
 
 ////////////////////////////////////////////////////////////////
 
-impl Translation for Duelist {
-    fn set_acceleration(&mut self, acceleration: Vec2) {
-        accelerate(acceleration);
-        self.acceleration = acceleration;
+impl Translation for Hull {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::FIGHTER);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
     }
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl Rotation for Duelist {
+impl Rotation for Hull {
     fn set_angular_acceleration(&mut self, acceleration: f64) {
         torque(acceleration)
     }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::FIGHTER.angular;
+    }
 }
 
 ////////////////////////////////////////////////////////////////
 
-impl Duelist {
-    const BULLET_SPEED: f64 = 1000.0; // m/s
-
-    pub fn new() -> Self {
-        return Self {
-            radar: CompositeRadar::new(UniqueContactBoard::new()),
-            radio: Radio::new(),
-
-            acceleration: vec2(0.0, 0.0),
+impl Machine for Hull {
+    type State = Duel;
 
-            enemy_fighter: None,
-            enemy_missile: None,
+    fn enter(&mut self, state: Duel) {
+        let id = match state {
+            Duel::Engage { fighter } => fighter,
+            Duel::Defend { missile, .. } => missile,
+            _ => return,
         };
-    }
-}
 
-////////////////////////////////////////////////////////////////
-
-impl Duelist {
-    fn fire_guns(&self) {
-        fire(0);
+        if let Err(error) = self.radar.start_tracking(id) {
+            error::report(error);
+        }
     }
 
-    fn launch_missile(&mut self) {
-        fire(1);
-        self.radio.set_channel(0);
-
-        if let Some((_, target)) = self
-            .radar
-            .contacts
-            .iter()
-            .find(|(_, c)| matches!(c, Contact::Tracked(_)))
-        {
-            self.radio.send(RadioMessage::Position(target.position()));
+    fn exit(&mut self, state: Duel) {
+        match state {
+            Duel::Engage { fighter } => self.radar.stop_tracking(fighter),
+            Duel::Defend { missile, .. } => self.radar.stop_tracking(missile),
+            _ => (),
         }
     }
-}
 
-////////////////////////////////////////////////////////////////
+    fn transition(&self, state: Duel, event: &Event, states: &StateMachine<Duel>) -> Option<Duel> {
+        let class = |id: &usize| self.radar.contacts.get(*id).map(|c| c.class());
 
-impl ShipClassLoop for Duelist {
-    fn tick(&mut self) {
-        debug!("Role: Duelist");
-
-        // Update radar contacts.
-        self.radar.scan(&self.position());
-
-        let get_contact_and_id = |id| self.radar.contacts.get(id).map(|c| (id, c));
-        let get_contact = |id| self.radar.contacts.get(id);
-
-        // Find the current target.
-        let enemy_fighter = self.enemy_fighter.or_else(|| self.get_enemy_fighter());
+        return match (state, event) {
+            (Duel::Search, Event::ContactFound(id)) if class(id) == Some(Class::Fighter) => {
+                Some(Duel::Track { fighter: *id })
+            }
 
-        // Find an incoming missile.
-        let enemy_missile = self
-            .enemy_missile
-            .or_else(|| self.get_closest_enemy_missile());
+            // There should only be 1 enemy fighter in this scenario.
+            (Duel::Search, Event::Tick) => self
+                .radar
+                .contacts
+                .iter()
+                .find(|(_, c)| c.class() == Class::Fighter)
+                .map(|(id, _)| Duel::Track { fighter: *id }),
 
-        // Configure the radar job rotation.
-        let mut start_tracking = Vec::new();
-        let mut stop_tracking = Vec::new();
+            (Duel::Engage { fighter }, Event::ContactLost(id)) if *id == fighter => {
+                Some(Duel::Search)
+            }
 
-        if let Some((id, Contact::Search(_))) = enemy_fighter.and_then(get_contact_and_id) {
-            debug!("new enemy fighter");
+            (Duel::Track { fighter }, Event::ContactTracked(id)) if *id == fighter => {
+                Some(Duel::Attack { fighter })
+            }
 
-            if let Some(old_id) = self.enemy_fighter {
-                if old_id != id {
-                    stop_tracking.push(old_id);
-                }
+            (Duel::Track { fighter } | Duel::Attack { fighter }, Event::ContactFound(id))
+                if class(id) == Some(Class::Missile) =>
+            {
+                Some(Duel::Defend {
+                    fighter,
+                    missile: *id,
+                })
             }
 
-            start_tracking.push(id);
-        }
+            (Duel::Defend { fighter, missile }, Event::ContactLost(id)) if *id == missile => {
+                Some(Duel::Attack { fighter })
+            }
 
-        if enemy_fighter.is_none() {
-            if let Some(id) = self.enemy_fighter {
-                stop_tracking.push(id);
+            (Duel::Defend { fighter, .. }, Event::Tick)
+                if states.ticks_in(state) > Some(DEFEND_TIMEOUT) =>
+            {
+                Some(Duel::Attack { fighter })
             }
-        }
 
-        // Manage the job for tracking an incoming missile.
-        if let Some((id, Contact::Search(_))) = enemy_missile.and_then(get_contact_and_id) {
-            debug!("new enemy missile");
+            _ => None,
+        };
+    }
 
-            if let Some(old_id) = self.enemy_missile {
-                if old_id != id {
-                    stop_tracking.push(old_id);
-                }
+    fn run(&mut self, state: Duel, _: &StateMachine<Duel>) {
+        match state {
+            Duel::Search => {
+                let map_centre = vec2(0.0, 0.0);
+                self.turn_to_face(&map_centre);
+                self.accelerate_towards(&map_centre);
             }
-            start_tracking.push(id);
-        }
 
-        if enemy_missile.is_none() {
-            if let Some(old_id) = self.enemy_missile {
-                stop_tracking.push(old_id);
-            }
+            Duel::Engage { fighter } | Duel::Track { fighter } => self.close_on(fighter),
+            Duel::Attack { fighter } => self.engage(fighter, fighter, true),
+            Duel::Defend { fighter, missile } => self.engage(missile, fighter, false),
         }
+    }
+}
 
-        // Enagage a target. Prioritise missiles.
-        let target = if let Some(Contact::Tracked(contact)) = enemy_missile.and_then(get_contact) {
-            Some(contact)
-        } else if let Some(Contact::Tracked(contact)) = enemy_fighter.and_then(get_contact) {
-            Some(contact)
-        } else {
-            None
+////////////////////////////////////////////////////////////////
+
+impl Duelist {
+    pub fn new() -> Self {
+        return Self {
+            hull: Hull {
+                radar: CompositeRadar::new(UniqueContactBoard::new()),
+                radio: Transport::new(Radio::new()),
+                channels: ChannelManager::new(id() as u16),
+                trigger: Trigger::new(HIT_PROBABILITY),
+
+                acceleration: vec2(0.0, 0.0),
+            },
+            radar_events: RadarEvents::new(),
+            states: StateMachine::new(Duel::Search),
         };
+    }
+}
 
-        let firing_solution = target.and_then(|c| FiringSolution::new(self, Self::BULLET_SPEED, c));
-
-        // Decide where to move.
-        if let Some(fighter) = enemy_fighter.and_then(get_contact) {
-            let pos = fighter.position();
-            self.turn_to_face(&pos);
-            self.accelerate_towards(&pos);
-        } else {
-            let map_centre = vec2(0.0, 0.0);
-            self.turn_to_face(&map_centre);
-            self.accelerate_towards(&map_centre);
+////////////////////////////////////////////////////////////////
+
+impl Hull {
+    fn fire_guns(&self) {
+        for gun in Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_gun) {
+            gun.fire();
         }
+    }
 
-        // Save the targets.
-        self.enemy_fighter = enemy_fighter;
-        self.enemy_missile = enemy_missile;
+    fn launch_missile(&mut self) {
+        let launchers = Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_launcher);
+        Weapon::launch(launchers, &mut self.channels);
+    }
 
-        if let Some(solution) = firing_solution {
-            debug!("Engaging target");
-            self.turn_to_track(&solution);
-            self.accelerate_towards(&solution);
+    /// Description
+    /// -----------
+    /// Head for a contact.
+    ///
+    fn close_on(&mut self, id: usize) {
+        if let Some(position) = self.radar.contacts.get(id).map(|c| c.position()) {
+            self.turn_to_face(&position);
+            self.accelerate_towards(&position);
+        }
+    }
 
-            if self.relative_bearing_to(&solution).abs() < (PI / 4.0) {
-                self.launch_missile();
+    /// Description
+    /// -----------
+    /// Shoot at a contact while closing on another.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `target` - Contact to shoot at.
+    /// * `fighter` - Contact to close on if the target can't be shot at yet.
+    /// * `missiles` - Whether to launch missiles at the target.
+    ///
+    fn engage(&mut self, target: usize, fighter: usize, missiles: bool) {
+        let solution = match self.radar.contacts.get(target) {
+            Some(Contact::Tracked(contact)) => {
+                FiringSolution::new(self, ShipStats::FIGHTER.weapons[0].speed, contact).ok()
             }
+            _ => None,
+        };
 
-            if self.relative_bearing_to(&solution).abs() < 0.02 {
-                self.fire_guns();
-            }
+        let Some(solution) = solution else {
+            self.close_on(fighter);
+            return;
+        };
 
-            draw::aim_reticle(&solution);
-        }
+        debug!("Engaging target");
+        self.turn_to_track(&solution);
+        self.accelerate_towards(&solution);
 
-        // Start or stop tracking targets.
-        for id in stop_tracking {
-            self.radar.stop_tracking(id);
+        if missiles && self.relative_bearing_to(&solution).abs() < (PI / 4.0) {
+            self.launch_missile();
         }
 
-        for id in start_tracking {
-            if let Err(error) = self.radar.start_tracking(id) {
-                debug!("ERROR - {:?}", error);
-            }
+        let contact = self.radar.contacts.get(target);
+        if contact.is_some_and(|contact| self.trigger.pull_on(self, &solution, contact)) {
+            self.fire_guns();
         }
 
-        self.radar.adjust(&KinematicModel::from(&*self));
-        // draw::heading(self);
-        self.radar.draw_contacts();
+        draw::aim_reticle(&solution);
     }
 }
 
@@ -251,30 +323,60 @@ impl ShipClassLoop for Duelist {
 impl Duelist {
     /// Description
     /// -----------
-    /// Get the id and contact of an enemy fighter from the contact board.
-    /// There should only be 1 enemy fighter in this scenario.
+    /// Send the enemy fighter to missiles in flight.
     ///
-    fn get_enemy_fighter(&self) -> Option<usize> {
-        return self
-            .radar
-            .contacts
-            .iter()
-            .find(|(_, c)| c.class() == Class::Fighter)
-            .map(|(id, _)| *id);
+    fn update_missiles(&mut self, channel: usize) {
+        let fighter = match self.states.state().parent() {
+            Some(Duel::Engage { fighter }) => self.hull.radar.contacts.get(fighter),
+            _ => None,
+        };
+
+        if let Some(Contact::Tracked(target)) = fighter {
+            self.hull.radio.send(
+                BROADCAST,
+                RadioMessage::MissileDatalink(MissileDatalink {
+                    channel,
+                    class: target.class(),
+                    position: target.position(),
+                    velocity: target.velocity(),
+                    time: target.time(),
+                }),
+            );
+        }
     }
+}
 
-    /// Description
-    /// -----------
-    /// Get the id and contact of the closest incoming missile from the contact board.
-    ///
-    fn get_closest_enemy_missile(&self) -> Option<usize> {
-        return self
-            .radar
-            .contacts
-            .iter()
-            .filter(|(_, c)| c.class() == Class::Missile)
-            .min_by_key(|(_, c)| c.distance_to(self) as u32)
-            .map(|(id, _)| *id);
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for Duelist {
+    fn tick(&mut self) {
+        debug!("Role: Duelist");
+
+        // Update radar contacts.
+        self.hull.radar.scan(&self.hull.position());
+
+        let mut messages = Vec::new();
+        while let Some((_, message)) = self.hull.radio.receive() {
+            messages.push(message);
+        }
+
+        let mut events = self.radar_events.update(&self.hull.radar);
+        events.extend(messages.iter().map(Event::Message));
+
+        self.states.update(&mut self.hull, &events);
+        self.states.draw(&self.hull);
+
+        self.hull.radar.adjust(&KinematicModel::from(&self.hull));
+        // draw::heading(self);
+        self.hull.radar.draw_contacts();
+
+        let tuning = self.hull.channels.tune(current_tick());
+        if let Tuning::Launch(channel) | Tuning::Salvo(channel) = tuning {
+            self.update_missiles(channel);
+        }
+
+        let next = self.hull.channels.plan(current_tick());
+        self.hull.radio.flush(tuning, next);
     }
 }
 
@@ -5,9 +5,16 @@
 
 mod default;
 mod duelist;
+mod skirmisher;
+mod striker;
 
 // Imports.
-use super::{class, control, draw, math, radar, radio};
+use super::{
+    behaviour, class, control, defence, dispersion, draw, ecs, error, fleet, math, radar, radio,
+    stats, trigger, weapon,
+};
 
 // Exports.
-pub use self::{default::DefaultFighter, duelist::Duelist};
+pub use self::{
+    default::DefaultFighter, duelist::Duelist, skirmisher::Skirmisher, striker::Striker,
+};
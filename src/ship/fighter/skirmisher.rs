@@ -0,0 +1,274 @@
+use oort_api::prelude::*;
+
+use super::{
+    behaviour::{
+        AcquireTarget, Action, BehaviourTree, Blackboard, Combatant, Condition, Decoration,
+        Decorator, EngageGuns, Evade, LaunchMissile, Parallel, Selector, Sequence, Status, Track,
+        TARGET, THREAT,
+    },
+    class::ShipClassLoop,
+    control::{Rotation, Thrust, Translation},
+    math::kinematics::{
+        Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity,
+    },
+    radar::{
+        board::{ContactBoard, UniqueContactBoard},
+        contacts::{Contact, RadarContact},
+        CompositeRadar,
+    },
+    radio::{ChannelManager, MissileDatalink, Radio, RadioMessage, Transport, Tuning, BROADCAST},
+    stats::{MaxAcceleration, ShipStats},
+    trigger::Trigger,
+    weapon::{Outcome, Weapon},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Lowest chance of a hit worth firing the guns for.
+const HIT_PROBABILITY: f64 = 0.25;
+
+/// Range within which incoming missiles are dodged.
+const THREAT_RANGE: f64 = 2000.0; // m
+
+/// Time to wait for the tracking radar to pick up a target before looking again.
+const TRACK_TIMEOUT: u32 = 120; // ticks
+
+/// Time between missile launches, to give each one a chance before the next.
+const LAUNCH_INTERVAL: u32 = 180; // ticks
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// The parts of the skirmisher controlled by it's behaviour tree.
+///
+struct Hull {
+    radar: CompositeRadar,
+    radio: Transport,
+    channels: ChannelManager,
+
+    acceleration: Vec2,
+}
+
+/// Description
+/// -----------
+/// Fighter role composed from behaviour tree nodes rather than hand-written. Dodges incoming
+/// missiles, otherwise hunts down the closest fighter with guns and missiles.
+///
+pub struct Skirmisher {
+    hull: Hull,
+    behaviour: BehaviourTree<Hull>,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for Hull {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for Hull {
+    fn velocity(&self) -> Vec2 {
+        return velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for Hull {
+    fn acceleration(&self) -> Vec2 {
+        return self.acceleration;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for Hull {
+    fn heading(&self) -> f64 {
+        return heading();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for Hull {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Translation for Hull {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::FIGHTER);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Rotation for Hull {
+    fn set_angular_acceleration(&mut self, acceleration: f64) {
+        torque(acceleration)
+    }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::FIGHTER.angular;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Combatant for Hull {
+    fn radar(&self) -> &CompositeRadar {
+        return &self.radar;
+    }
+
+    fn radar_mut(&mut self) -> &mut CompositeRadar {
+        return &mut self.radar;
+    }
+
+    fn projectile_speed(&self) -> f64 {
+        return ShipStats::FIGHTER.weapons[0].speed;
+    }
+
+    fn max_acceleration(&self) -> f64 {
+        let max_acceleration = MaxAcceleration::FIGHTER;
+        return f64::min(max_acceleration.lateral, max_acceleration.reverse);
+    }
+
+    fn fire_guns(&mut self) {
+        for gun in Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_gun) {
+            gun.fire();
+        }
+    }
+
+    fn launch_missile(&mut self) -> Outcome {
+        let launchers = Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_launcher);
+        return Weapon::launch(launchers, &mut self.channels);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Skirmisher {
+    pub fn new() -> Self {
+        let evade = Sequence::new(vec![
+            Box::new(AcquireTarget::new(THREAT, Class::Missile).within(THREAT_RANGE)),
+            Box::new(Condition::new(|hull: &Hull, blackboard: &Blackboard| {
+                // Ignore missiles that have already gone past.
+                let threat = blackboard
+                    .contact(THREAT)
+                    .and_then(|id| hull.radar.contacts.get(id));
+
+                return threat.is_some_and(|threat| {
+                    let offset = hull.position() - threat.position();
+                    let closing = threat.velocity() - hull.velocity();
+                    offset.dot(closing) > 0.0
+                });
+            })),
+            Box::new(Evade::new(THREAT)),
+        ]);
+
+        let attack = Sequence::new(vec![
+            Box::new(AcquireTarget::new(TARGET, Class::Fighter)),
+            Box::new(Decorator::new(
+                Decoration::Timeout(TRACK_TIMEOUT),
+                Box::new(Track::new(TARGET)),
+            )),
+            Box::new(Parallel::new(
+                vec![
+                    Box::new(EngageGuns::new(TARGET, Trigger::new(HIT_PROBABILITY))),
+                    Box::new(Decorator::new(
+                        Decoration::Cooldown(LAUNCH_INTERVAL),
+                        Box::new(LaunchMissile::new(TARGET)),
+                    )),
+                ],
+                1,
+            )),
+        ]);
+
+        let patrol = Action::new(|hull: &mut Hull, _: &mut Blackboard| {
+            let map_centre = vec2(0.0, 0.0);
+            hull.turn_to_face(&map_centre);
+            hull.accelerate_towards(&map_centre);
+            return Status::Running;
+        });
+
+        return Self {
+            hull: Hull {
+                radar: CompositeRadar::new(UniqueContactBoard::new()),
+                radio: Transport::new(Radio::new()),
+                channels: ChannelManager::new(id() as u16),
+
+                acceleration: vec2(0.0, 0.0),
+            },
+            behaviour: BehaviourTree::new(Box::new(Selector::new(vec![
+                Box::new(evade),
+                Box::new(attack),
+                Box::new(patrol),
+            ]))),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Skirmisher {
+    /// Description
+    /// -----------
+    /// Send the current target to missiles in flight.
+    ///
+    fn update_missiles(&mut self, channel: usize) {
+        let target = self
+            .behaviour
+            .blackboard()
+            .contact(TARGET)
+            .and_then(|id| self.hull.radar.contacts.get(id));
+
+        if let Some(Contact::Tracked(target)) = target {
+            self.hull.radio.send(
+                BROADCAST,
+                RadioMessage::MissileDatalink(MissileDatalink {
+                    channel,
+                    class: target.class(),
+                    position: target.position(),
+                    velocity: target.velocity(),
+                    time: target.time(),
+                }),
+            );
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for Skirmisher {
+    fn tick(&mut self) {
+        debug!("Role: Skirmisher");
+
+        self.hull.radar.scan(&self.hull.position());
+
+        let status = self.behaviour.tick(&mut self.hull);
+        debug!("Behaviour: {status:?}");
+
+        self.hull.radar.adjust(&KinematicModel::from(&self.hull));
+        self.hull.radar.draw_contacts();
+
+        let tuning = self.hull.channels.tune(current_tick());
+        if let Tuning::Launch(channel) | Tuning::Salvo(channel) = tuning {
+            self.update_missiles(channel);
+        }
+
+        let next = self.hull.channels.plan(current_tick());
+        self.hull.radio.flush(tuning, next);
+    }
+}
+
+////////////////////////////////////////////////////////////////
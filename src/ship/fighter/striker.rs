@@ -0,0 +1,398 @@
+use std::{cell::RefCell, rc::Rc};
+
+use oort_api::prelude::*;
+
+use super::{
+    behaviour::{Event, Executor, RadarEvents, Signals},
+    class::ShipClassLoop,
+    control::{Rotation, Thrust, Translation},
+    error,
+    math::{
+        kinematics::{Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity},
+        FiringSolution,
+    },
+    radar::{
+        board::{ContactBoard, UniqueContactBoard},
+        contacts::{Contact, RadarContact},
+        CompositeRadar,
+    },
+    radio::{
+        ChannelManager, MissileDatalink, Order, Radio, RadioMessage, Transport, Tuning, BROADCAST,
+    },
+    stats::{MaxAcceleration, ShipStats},
+    trigger::Trigger,
+    weapon::{Outcome, Weapon},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Heading error within which the burn starts.
+const ALIGN_TOLERANCE: f64 = 0.05; // rad
+
+/// Lowest chance of a hit worth firing the guns for during the burn.
+const HIT_PROBABILITY: f64 = 0.25;
+
+/// Length of the burn towards the target.
+const BURN_TIME: f64 = 3.0; // s
+
+/// Length of the break away from the target after firing.
+const BREAK_TIME: f64 = 2.0; // s
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// The parts of the striker shared with it's tasks.
+///
+struct Hull {
+    radar: CompositeRadar,
+    radio: Transport,
+    channels: ChannelManager,
+
+    acceleration: Vec2,
+    trigger: Trigger,
+
+    /// Latest fleet order received.
+    order: Option<Order>,
+}
+
+/// Description
+/// -----------
+/// Fighter role that makes repeated attack runs, each written as straight-line async code: turn
+/// to the target, burn for 3 s, fire, break.
+///
+pub struct Striker {
+    hull: Rc<RefCell<Hull>>,
+    radar_events: RadarEvents,
+    executor: Executor,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for Hull {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for Hull {
+    fn velocity(&self) -> Vec2 {
+        return velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for Hull {
+    fn acceleration(&self) -> Vec2 {
+        return self.acceleration;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for Hull {
+    fn heading(&self) -> f64 {
+        return heading();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for Hull {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Translation for Hull {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::FIGHTER);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Rotation for Hull {
+    fn set_angular_acceleration(&mut self, acceleration: f64) {
+        torque(acceleration)
+    }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::FIGHTER.angular;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Hull {
+    /// Description
+    /// -----------
+    /// Return the closest fighter on the board.
+    ///
+    fn closest_fighter(&self) -> Option<usize> {
+        return self
+            .radar
+            .contacts
+            .iter()
+            .filter(|(_, c)| c.class() == Class::Fighter)
+            .min_by(|(_, a), (_, b)| a.distance_to(self).total_cmp(&b.distance_to(self)))
+            .map(|(id, _)| *id);
+    }
+
+    /// Description
+    /// -----------
+    /// Return where to aim to hit a contact. Falls back to it's current position until it's
+    /// tracked.
+    ///
+    fn aim_point(&self, id: usize) -> Option<Vec2> {
+        return match self.radar.contacts.get(id)? {
+            Contact::Tracked(contact) => {
+                FiringSolution::new(self, ShipStats::FIGHTER.weapons[0].speed, contact)
+                    .map(|solution| solution.position())
+                    .ok()
+            }
+            contact => Some(contact.position()),
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return whether a shot at a contact fired now is likely enough to hit. Only tracked
+    /// contacts are known well enough to shoot at.
+    ///
+    fn on_target(&self, id: usize) -> bool {
+        let Some(contact) = self.radar.contacts.get(id) else {
+            return false;
+        };
+
+        let Contact::Tracked(tracked) = contact else {
+            return false;
+        };
+
+        let speed = ShipStats::FIGHTER.weapons[0].speed;
+        return FiringSolution::new(self, speed, tracked)
+            .is_ok_and(|solution| self.trigger.pull_on(self, &solution, contact));
+    }
+
+    fn fire_guns(&self) {
+        for gun in Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_gun) {
+            gun.fire();
+        }
+    }
+
+    fn launch_missile(&mut self) -> Outcome {
+        let launchers = Weapon::loadout(ShipStats::FIGHTER.weapons).filter(Weapon::is_launcher);
+        return Weapon::launch(launchers, &mut self.channels);
+    }
+
+    /// Description
+    /// -----------
+    /// Send the target of the attack run to missiles in flight.
+    ///
+    fn update_missiles(&mut self, channel: usize) {
+        if let Some((_, Contact::Tracked(target))) = self
+            .radar
+            .contacts
+            .iter()
+            .find(|(_, c)| matches!(c, Contact::Tracked(_)))
+        {
+            self.radio.send(
+                BROADCAST,
+                RadioMessage::MissileDatalink(MissileDatalink {
+                    channel,
+                    class: target.class(),
+                    position: target.position(),
+                    velocity: target.velocity(),
+                    time: target.time(),
+                }),
+            );
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Striker {
+    pub fn new() -> Self {
+        let hull = Rc::new(RefCell::new(Hull {
+            radar: CompositeRadar::new(UniqueContactBoard::new()),
+            radio: Transport::new(Radio::new()),
+            channels: ChannelManager::new(id() as u16),
+
+            acceleration: vec2(0.0, 0.0),
+            trigger: Trigger::new(HIT_PROBABILITY),
+
+            order: None,
+        }));
+
+        let mut executor = Executor::new();
+        executor.spawn(attack(hull.clone(), executor.signals()));
+        executor.spawn(follow_orders(hull.clone(), executor.signals()));
+
+        return Self {
+            hull,
+            radar_events: RadarEvents::new(),
+            executor,
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Make attack runs on the closest fighter, waiting for one to turn up if there isn't one. Holds
+/// off while the fleet's retreating.
+///
+async fn attack(hull: Rc<RefCell<Hull>>, signals: Signals) {
+    loop {
+        if matches!(hull.borrow().order, Some(Order::Retreat(_))) {
+            signals.next_tick().await;
+            continue;
+        }
+
+        let target = hull.borrow().closest_fighter();
+        let Some(target) = target else {
+            signals
+                .radar_event(|event| matches!(event, Event::ContactFound(_)))
+                .await;
+            continue;
+        };
+
+        attack_run(&hull, &signals, target).await;
+        hull.borrow_mut().radar.stop_tracking(target);
+
+        // Runs can end straight away, e.g. if the target's lost.
+        signals.next_tick().await;
+    }
+}
+
+/// Description
+/// -----------
+/// Turn to the target, burn towards it for 3 s, fire, then break away. Gives up if the target's
+/// lost part way through.
+///
+async fn attack_run(hull: &Rc<RefCell<Hull>>, signals: &Signals, target: usize) {
+    if let Err(error) = hull.borrow_mut().radar.start_tracking(target) {
+        error::report(error);
+        return;
+    }
+
+    let max_acceleration = MaxAcceleration::FIGHTER;
+
+    // Turn to heading.
+    loop {
+        let Some(aim) = hull.borrow().aim_point(target) else {
+            return;
+        };
+
+        let bearing = hull.borrow().relative_bearing_to(&aim);
+        if bearing.abs() < ALIGN_TOLERANCE {
+            break;
+        }
+
+        hull.borrow_mut().turn_to_face(&aim);
+        signals.next_tick().await;
+    }
+
+    // Burn.
+    for _ in 0..(BURN_TIME / TICK_LENGTH) as u32 {
+        let Some(aim) = hull.borrow().aim_point(target) else {
+            return;
+        };
+
+        {
+            let mut hull = hull.borrow_mut();
+            let fire = hull.on_target(target);
+
+            hull.turn_to_face(&aim);
+            let forward = vec2(max_acceleration.forward, 0.0).rotate(hull.heading());
+            hull.set_acceleration(forward);
+
+            if fire {
+                hull.fire_guns();
+            }
+        }
+
+        signals.next_tick().await;
+    }
+
+    // Fire, waiting for the radio to be ready to hand the target over.
+    while hull.borrow_mut().launch_missile() == Outcome::Waiting {
+        signals.next_tick().await;
+    }
+
+    // Break.
+    let away = hull.borrow().heading() + (PI / 2.0);
+    for _ in 0..(BREAK_TIME / TICK_LENGTH) as u32 {
+        {
+            let mut hull = hull.borrow_mut();
+            hull.turn_to_heading(away);
+            hull.set_acceleration(vec2(max_acceleration.lateral, 0.0).rotate(away));
+        }
+
+        signals.next_tick().await;
+    }
+}
+
+/// Description
+/// -----------
+/// Keep track of the latest fleet order.
+///
+async fn follow_orders(hull: Rc<RefCell<Hull>>, signals: Signals) {
+    loop {
+        let message = signals
+            .radio_message(|message| matches!(message, RadioMessage::FleetOrder(_)))
+            .await;
+
+        if let RadioMessage::FleetOrder(order) = message {
+            hull.borrow_mut().order = Some(order.order);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for Striker {
+    fn tick(&mut self) {
+        debug!("Role: Striker");
+
+        let (events, messages) = {
+            let mut hull = self.hull.borrow_mut();
+            let position = hull.position();
+            hull.radar.scan(&position);
+
+            let mut messages = Vec::new();
+            while let Some((_, message)) = hull.radio.receive() {
+                messages.push(message);
+            }
+
+            (self.radar_events.update(&hull.radar), messages)
+        };
+
+        self.executor.poll(events, messages);
+
+        let mut hull = self.hull.borrow_mut();
+        let model = KinematicModel::from(&*hull);
+        hull.radar.adjust(&model);
+        hull.radar.draw_contacts();
+
+        let tuning = hull.channels.tune(current_tick());
+        if let Tuning::Launch(channel) | Tuning::Salvo(channel) = tuning {
+            hull.update_missiles(channel);
+        }
+
+        let next = hull.channels.plan(current_tick());
+        hull.radio.flush(tuning, next);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,381 @@
+use oort_api::prelude::*;
+
+use super::{
+    class::ShipClassLoop,
+    control::{Rotation, Thrust, Translation},
+    draw, error,
+    math::{
+        kinematics::{Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity},
+        FiringSolution,
+    },
+    radar::{
+        board::{ContactBoard, UniqueContactBoard},
+        contacts::{Contact, RadarContact},
+        CompositeRadar,
+    },
+    radio::{ChannelManager, MissileDatalink, Radio, RadioMessage, Transport, Tuning, BROADCAST},
+    stats::{MaxAcceleration, ShipStats},
+    trigger::Trigger,
+    weapon::{Turret, Weapon},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Lowest chance of a hit worth firing the main gun for. It's slow to reload so make it count.
+const HIT_PROBABILITY: f64 = 0.5;
+
+/// Lowest chance of a hit worth firing a turret for. They reload quickly so can afford to miss.
+const TURRET_HIT_PROBABILITY: f64 = 0.2;
+
+/// Bearing within which missiles are launched.
+const MISSILE_BEARING: f64 = PI / 4.0; // rad
+
+/// Distance to keep from the primary target. Far enough to see missiles coming, close enough for
+/// the main gun to hit.
+const STANDOFF_RANGE: f64 = 4000.0; // m
+
+/// Slowest closing speed used when ranking threats. Stops contacts that are holding their
+/// distance or moving away being ranked as no threat at all.
+const MIN_CLOSING_SPEED: f64 = 10.0; // m/s
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Frigate role. Holds off from the most dangerous enemy ship and shoots at it with the main gun
+/// and missiles, while each turret shoots at the most urgent threat it can get a solution on.
+///
+pub struct DefaultFrigate {
+    radar: CompositeRadar,
+    radio: Transport,
+    channels: ChannelManager,
+
+    turrets: Vec<Turret>,
+
+    /// Contacts being tracked, most urgent first.
+    threats: Vec<usize>,
+    trigger: Trigger,
+    turret_trigger: Trigger,
+
+    acceleration: Vec2,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for DefaultFrigate {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for DefaultFrigate {
+    fn velocity(&self) -> Vec2 {
+        return velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for DefaultFrigate {
+    fn acceleration(&self) -> Vec2 {
+        return self.acceleration;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for DefaultFrigate {
+    fn heading(&self) -> f64 {
+        return heading();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for DefaultFrigate {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Translation for DefaultFrigate {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::FRIGATE);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Rotation for DefaultFrigate {
+    fn set_angular_acceleration(&mut self, acceleration: f64) {
+        torque(acceleration)
+    }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::FRIGATE.angular;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl DefaultFrigate {
+    pub fn new() -> Self {
+        return Self {
+            radar: CompositeRadar::new(UniqueContactBoard::new()),
+            radio: Transport::new(Radio::new()),
+            channels: ChannelManager::new(id() as u16),
+
+            turrets: Weapon::loadout(ShipStats::FRIGATE.weapons)
+                .filter_map(Turret::new)
+                .collect(),
+
+            threats: Vec::new(),
+            trigger: Trigger::new(HIT_PROBABILITY),
+            turret_trigger: Trigger::new(TURRET_HIT_PROBABILITY),
+
+            acceleration: vec2(0.0, 0.0),
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return how urgently a contact needs dealing with, lower first. Based on how long it'll
+    /// take to reach us, with missiles brought forward and big ships pushed back.
+    ///
+    /// Returns
+    /// -------
+    /// None if the contact can't hurt us.
+    ///
+    fn threat<C: Velocity>(&self, class: Class, contact: &C) -> Option<f64> {
+        let weight = match class {
+            Class::Missile | Class::Torpedo => 0.5,
+            Class::Fighter => 1.0,
+            Class::Frigate | Class::Cruiser => 2.0,
+            _ => return None,
+        };
+
+        let offset = self.position_relative_to(contact);
+        let distance = offset.length();
+        let closing_speed = contact.velocity_relative_to(self).dot(offset) / distance.max(1.0);
+
+        return Some(weight * distance / closing_speed.max(MIN_CLOSING_SPEED));
+    }
+
+    /// Description
+    /// -----------
+    /// Rank every contact by threat and track the ones the guns will be shooting at.
+    ///
+    fn update_threats(&mut self) {
+        let mut ranked: Vec<(usize, f64)> = self
+            .radar
+            .contacts
+            .iter()
+            .filter_map(|(id, contact)| Some((*id, self.threat(contact.class(), contact)?)))
+            .collect();
+        ranked.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        // Enough for each turret and the main gun to have their own target.
+        let turrets = Weapon::loadout(ShipStats::FRIGATE.weapons)
+            .filter(Weapon::has_turret)
+            .count();
+        let threats: Vec<usize> = ranked
+            .into_iter()
+            .map(|(id, _)| id)
+            .take(turrets + 1)
+            .collect();
+
+        for id in self.threats.iter().filter(|id| !threats.contains(id)) {
+            self.radar.stop_tracking(*id);
+        }
+
+        for id in threats.iter().filter(|id| !self.threats.contains(id)) {
+            if let Err(error) = self.radar.start_tracking(*id) {
+                error::report(error);
+            }
+        }
+
+        self.threats = threats;
+    }
+
+    /// Description
+    /// -----------
+    /// Return the most urgent ship to fight with the main gun. Missiles are left to the turrets.
+    ///
+    fn primary(&self) -> Option<usize> {
+        return self.threats.iter().copied().find(|id| {
+            self.radar
+                .contacts
+                .get(*id)
+                .is_some_and(|c| !matches!(c.class(), Class::Missile | Class::Torpedo))
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Return where a weapon needs to aim to hit a contact.
+    ///
+    fn solution(&self, id: usize, weapon: &Weapon) -> Option<FiringSolution> {
+        let Some(Contact::Tracked(contact)) = self.radar.contacts.get(id) else {
+            return None;
+        };
+
+        return FiringSolution::new(self, weapon.speed(), contact).ok();
+    }
+
+    /// Description
+    /// -----------
+    /// Aim each turret at the most urgent threat it has a solution for. Turrets are spread over
+    /// the threats so two aren't wasted on the same one while another gets through. Each only
+    /// fires once it's come round far enough to be likely to hit.
+    ///
+    fn fire_turrets(&mut self) {
+        let solutions: Vec<Option<(usize, FiringSolution)>> = self
+            .turrets
+            .iter()
+            .enumerate()
+            .map(|(index, turret)| {
+                self.threats
+                    .iter()
+                    .cycle()
+                    .skip(index)
+                    .take(self.threats.len())
+                    .find_map(|id| Some((*id, self.solution(*id, turret.weapon())?)))
+            })
+            .collect();
+
+        for (turret, solution) in self.turrets.iter_mut().zip(solutions) {
+            let Some((id, solution)) = solution else {
+                continue;
+            };
+
+            turret.aim(solution.aim_heading());
+
+            let contact = self.radar.contacts.get(id);
+            if contact
+                .is_some_and(|contact| self.turret_trigger.pull_on(&*turret, &solution, contact))
+            {
+                turret.fire();
+            }
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Hold station at standoff range from the primary target, pointing the main gun at it.
+    ///
+    fn engage(&mut self, id: usize) {
+        let Some(contact) = self.radar.contacts.get(id) else {
+            return;
+        };
+
+        // Back off astern if we're right on top of it, as there's no direction away from it.
+        let offset = self.position_relative_to(contact);
+        let direction = if offset.length() > 0.0 {
+            offset.normalize()
+        } else {
+            -vec2(1.0, 0.0).rotate(self.heading())
+        };
+        let waypoint = KinematicModel::new(
+            contact.position() + (direction * STANDOFF_RANGE),
+            contact.velocity(),
+            vec2(0.0, 0.0),
+        );
+        let aim = contact.position();
+
+        let max_acceleration = ShipStats::FRIGATE.acceleration;
+        let max_acceleration = f64::min(max_acceleration.lateral, max_acceleration.reverse);
+        self.hold_station(&waypoint, max_acceleration, f64::INFINITY);
+
+        // The main gun is the one fixed along the ship's heading.
+        let main_gun =
+            Weapon::loadout(ShipStats::FRIGATE.weapons).find(|w| w.is_gun() && !w.has_turret());
+        let Some(main_gun) = main_gun else {
+            self.turn_to_face(&aim);
+            return;
+        };
+
+        let Some(solution) = self.solution(id, &main_gun) else {
+            self.turn_to_face(&aim);
+            return;
+        };
+
+        self.turn_to_track(&solution);
+
+        let contact = self.radar.contacts.get(id);
+        if contact.is_some_and(|contact| self.trigger.pull_on(self, &solution, contact)) {
+            main_gun.fire();
+        }
+
+        let bearing = self.relative_bearing_to(&solution).abs();
+
+        if bearing < MISSILE_BEARING {
+            let launchers = Weapon::loadout(ShipStats::FRIGATE.weapons).filter(Weapon::is_launcher);
+            Weapon::launch(launchers, &mut self.channels);
+        }
+
+        draw::aim_reticle(&solution);
+    }
+
+    /// Description
+    /// -----------
+    /// Send the primary target to missiles in flight.
+    ///
+    fn update_missiles(&mut self, channel: usize) {
+        let primary = self.primary().and_then(|id| self.radar.contacts.get(id));
+
+        if let Some(Contact::Tracked(target)) = primary {
+            self.radio.send(
+                BROADCAST,
+                RadioMessage::MissileDatalink(MissileDatalink {
+                    channel,
+                    class: target.class(),
+                    position: target.position(),
+                    velocity: target.velocity(),
+                    time: target.time(),
+                }),
+            );
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for DefaultFrigate {
+    fn tick(&mut self) {
+        debug!("Role: Frigate");
+
+        self.radar.scan(&self.position());
+
+        self.update_threats();
+        self.fire_turrets();
+
+        match self.primary() {
+            Some(id) => self.engage(id),
+            None => {
+                let map_centre = vec2(0.0, 0.0);
+                self.turn_to_face(&map_centre);
+                self.accelerate_towards(&map_centre);
+            }
+        }
+
+        self.radar.adjust(&KinematicModel::from(&*self));
+        self.radar.draw_contacts();
+
+        let tuning = self.channels.tune(current_tick());
+        if let Tuning::Launch(channel) | Tuning::Salvo(channel) = tuning {
+            self.update_missiles(channel);
+        }
+
+        let next = self.channels.plan(current_tick());
+        self.radio.flush(tuning, next);
+    }
+}
+
+////////////////////////////////////////////////////////////////
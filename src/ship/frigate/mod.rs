@@ -0,0 +1,7 @@
+mod default;
+
+// Imports.
+use super::{class, control, draw, error, math, radar, radio, stats, trigger, weapon};
+
+// Exports.
+pub use self::default::DefaultFrigate;
@@ -3,8 +3,9 @@ use oort_api::prelude::*;
 use crate::ship::class::ShipClassLoop;
 
 use super::{
-    control::{Rotation, Translation},
+    control::{Rotation, Thrust, Translation},
     draw::{self, Colour},
+    error,
     math::{
         kinematics::{Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity},
         Intercept,
@@ -14,13 +15,18 @@ use super::{
         contacts::{Contact, SearchContact, TrackedContact},
         CompositeRadar,
     },
-    radio::{Radio, RadioMessage},
+    radio::{Radio, RadioMessage, Transport},
     stats::MaxAcceleration,
 };
 
+/// Extra forward acceleration while boosting.
+const BOOST_ACCELERATION: f64 = 100.0; // m/s^2
+
+////////////////////////////////////////////////////////////////
+
 pub struct DefaultMissile {
     radar: CompositeRadar,
-    radio: Radio,
+    radio: Transport,
 
     target_position: Option<Vec2>,
 
@@ -70,18 +76,16 @@ impl AngularVelocity for DefaultMissile {
 ////////////////////////////////////////////////////////////////
 
 impl Translation for DefaultMissile {
-    fn set_acceleration(&mut self, acceleration: Vec2) {
-        let angle = angle_diff(self.heading(), acceleration.angle());
-        let forward = acceleration.length() * f64::cosh(angle);
-        let lateral = acceleration.length() * f64::sinh(angle);
-
-        let forward = forward.clamp(max_backward_acceleration(), max_forward_acceleration());
-        let lateral = lateral.clamp(-max_lateral_acceleration(), max_lateral_acceleration());
-
-        let accel = vec2(forward, lateral).rotate(acceleration.angle());
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let mut limits = MaxAcceleration::MISSILE;
+        if active_abilities().get_ability(Ability::Boost) {
+            limits.forward += BOOST_ACCELERATION;
+        }
 
-        accelerate(accel);
-        self.acceleration = accel;
+        let thrust = Thrust::allocate(acceleration, self.heading(), &limits);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
     }
 }
 
@@ -91,20 +95,30 @@ impl Rotation for DefaultMissile {
     fn set_angular_acceleration(&mut self, acceleration: f64) {
         torque(acceleration)
     }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::MISSILE.angular;
+    }
 }
 
 ////////////////////////////////////////////////////////////////
 
 impl DefaultMissile {
     pub fn new() -> Self {
-        let radio = Radio::new();
-        let target_position = if let Some(RadioMessage::Position(position)) = radio.receive() {
-            let heading = position.angle();
-            // radar.set_search_heading(heading - (PI / 8.0), heading + (PI / 8.0));
-            Some(position)
-        } else {
-            None
-        };
+        // The launcher hands over it's target on the launch channel, which we're tuned to at
+        // spawn, along with the salvo's own channel to listen on from then on.
+        let mut radio = Transport::new(Radio::new());
+        let target_position =
+            if let Some((_, RadioMessage::MissileDatalink(link))) = radio.receive() {
+                radio.radio().set_channel(link.channel);
+
+                let position = link.position;
+                let heading = position.angle();
+                // radar.set_search_heading(heading - (PI / 8.0), heading + (PI / 8.0));
+                Some(position)
+            } else {
+                None
+            };
 
         return Self {
             radar: CompositeRadar::new(UniqueContactBoard::new()),
@@ -119,6 +133,13 @@ impl DefaultMissile {
 
 impl ShipClassLoop for DefaultMissile {
     fn tick(&mut self) {
+        // Update the target from the launcher.
+        while let Some((_, message)) = self.radio.receive() {
+            if let RadioMessage::MissileDatalink(link) = message {
+                self.target_position = Some(link.position);
+            }
+        }
+
         // Update radar contacts.
         self.radar.scan(&self.position());
 
@@ -144,7 +165,7 @@ impl ShipClassLoop for DefaultMissile {
 
             if let Some((&id, _)) = closest_contact {
                 if let Err(error) = self.radar.start_tracking(id) {
-                    debug!("ERROR - {error:?}")
+                    error::report(error);
                 }
             }
 
@@ -154,15 +175,23 @@ impl ShipClassLoop for DefaultMissile {
         // Intercept the target.
         if let Some(Contact::Tracked(target)) = target {
             let acceleration = if active_abilities().get_ability(Ability::Boost) {
-                MaxAcceleration::from(Class::Missile).forward + 100.0
+                MaxAcceleration::MISSILE.forward + BOOST_ACCELERATION
             } else {
-                MaxAcceleration::from(Class::Missile).forward
+                MaxAcceleration::MISSILE.forward
             };
 
-            let solution = Intercept::new(self, acceleration, &target);
+            // Head straight for the target if it can't be intercepted.
+            let solution = match Intercept::new(self, acceleration, &target) {
+                Ok(solution) => solution.position(),
+                Err(error) => {
+                    error::report(error);
+                    target.position()
+                }
+            };
 
             self.turn_to_face(&solution);
-            self.accelerate_towards(&solution);
+            let direction = (solution - self.position()).normalize();
+            let thrust = self.set_acceleration(direction * acceleration);
 
             draw::aim_reticle(&solution);
 
@@ -171,7 +200,9 @@ impl ShipClassLoop for DefaultMissile {
             } else if target.distance_to(self) < 400.0 {
                 self.turn_to_track(&target);
                 draw::aim_reticle(&target);
-            } else if self.relative_bearing_to(&solution) < 0.3 {
+            } else if !thrust.is_saturated() {
+                // Only boost once we're lined up well enough to put all of it towards the
+                // target.
                 activate_ability(Ability::Boost);
             }
         }
@@ -182,3 +213,80 @@ impl ShipClassLoop for DefaultMissile {
 }
 
 ////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use oort_api::{sys::write_system_state, SystemState};
+
+    use super::*;
+    use crate::radio::{ChannelManager, MissileDatalink, Tuning, BROADCAST};
+
+    fn set_tick(tick: u32) {
+        write_system_state(SystemState::CurrentTick, tick as f64);
+    }
+
+    #[test]
+    fn test_handoff() {
+        let mut channels = ChannelManager::new(3);
+        let mut launcher = Transport::new(Radio::new());
+
+        // The launcher asks for the launch channel the tick before it fires, while on the fleet
+        // channel.
+        set_tick(9);
+        write_system_state(SystemState::Radio0Channel, 1.0);
+        channels.request_launch(9);
+        let tuning = channels.tune(9);
+        let next = channels.plan(9);
+        launcher.flush(tuning, next);
+        assert!(matches!(next, Tuning::Launch(_)));
+
+        // Fire, handing the target and the salvo's channel to the missile.
+        set_tick(10);
+        assert!(channels.launching(10));
+        let channel = channels.launch_salvo(10);
+        let tuning = channels.tune(10);
+        assert_eq!(tuning, Tuning::Launch(channel));
+        assert_eq!(get_radio_channel(), tuning.channel());
+
+        let target = vec2(1000.0, 2000.0);
+        launcher.send(
+            BROADCAST,
+            RadioMessage::MissileDatalink(MissileDatalink {
+                channel,
+                class: Class::Fighter,
+                position: target,
+                velocity: vec2(0.0, 0.0),
+                time: 10.0 * TICK_LENGTH,
+            }),
+        );
+        let next = channels.plan(10);
+        launcher.flush(tuning, next);
+
+        // The missile spawns on the next tick, hears the handoff on the launch channel and
+        // switches to the salvo's channel.
+        set_tick(11);
+        write_system_state(SystemState::Radio0Channel, tuning.channel() as f64);
+        write_system_state(SystemState::Radio0Receive, 1.0);
+
+        let missile = DefaultMissile::new();
+        assert_eq!(get_radio_channel(), channel);
+        assert!(missile
+            .target_position
+            .is_some_and(|position| position.distance(target) < 1.0));
+
+        for state in [
+            SystemState::CurrentTick,
+            SystemState::Radio0Channel,
+            SystemState::Radio0Send,
+            SystemState::Radio0Receive,
+            SystemState::Radio0Data0,
+            SystemState::Radio0Data1,
+            SystemState::Radio0Data2,
+            SystemState::Radio0Data3,
+        ] {
+            write_system_state(state, 0.0);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
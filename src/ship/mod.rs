@@ -1,11 +1,17 @@
 mod class;
+pub mod cruiser;
+pub mod dispersion;
 pub mod experimental;
 pub mod fighter;
+pub mod frigate;
 pub mod missile;
 pub mod stats;
+pub mod torpedo;
+pub mod trigger;
+pub mod weapon;
 
 // Imports.
-use super::{control, draw, math, radar, radio};
+use super::{behaviour, control, defence, draw, ecs, error, fleet, math, radar, radio};
 
 // Exports.
 pub use class::ShipClass;
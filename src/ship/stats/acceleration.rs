@@ -1,5 +1,7 @@
 use oort_api::prelude::*;
 
+use crate::error::Error;
+
 #[derive(Clone, PartialEq, PartialOrd, Debug)]
 pub struct MaxAcceleration {
     pub forward: f64,
@@ -8,54 +10,100 @@ pub struct MaxAcceleration {
     pub angular: f64,
 }
 
-impl From<Class> for MaxAcceleration {
-    fn from(value: Class) -> Self {
+impl TryFrom<Class> for MaxAcceleration {
+    type Error = Error;
+
+    fn try_from(value: Class) -> Result<Self, Error> {
         return match value {
-            Class::Fighter => Self {
-                forward: 60.0,
-                reverse: 30.0,
-                lateral: 30.0,
-                angular: 2.0 * std::f64::consts::PI,
-            },
-            Class::Frigate => Self {
-                forward: 10.0,
-                reverse: 5.0,
-                lateral: 5.0,
-                angular: std::f64::consts::PI / 4.0,
-            },
-            Class::Cruiser => Self {
-                forward: 5.0,
-                reverse: 2.5,
-                lateral: 2.5,
-                angular: std::f64::consts::PI / 8.0,
-            },
-            Class::Asteroid => Self {
-                forward: 0.0,
-                reverse: 0.0,
-                lateral: 0.0,
-                angular: 0.0,
-            },
-            Class::Target => todo!(),
-            Class::Missile => Self {
-                forward: 300.0,
-                reverse: 0.0,
-                lateral: 100.0,
-                angular: 4.0 * std::f64::consts::PI,
-            },
-            Class::Torpedo => Self {
-                forward: 70.0,
-                reverse: 0.0,
-                lateral: 20.0,
-                angular: 2.0 * std::f64::consts::PI,
-            },
-            Class::Unknown => panic!(),
+            Class::Fighter => Ok(Self::FIGHTER),
+            Class::Frigate => Ok(Self::FRIGATE),
+            Class::Cruiser => Ok(Self::CRUISER),
+            Class::Asteroid => Ok(Self::ASTEROID),
+            Class::Target => Ok(Self::TARGET),
+            Class::Missile => Ok(Self::MISSILE),
+            Class::Torpedo => Ok(Self::TORPEDO),
+            Class::Unknown => Err(Error::NoStats(value)),
         };
     }
 }
 
 impl MaxAcceleration {
+    pub const FIGHTER: Self = Self {
+        forward: 60.0,
+        reverse: 30.0,
+        lateral: 30.0,
+        angular: 2.0 * std::f64::consts::PI,
+    };
+
+    pub const FRIGATE: Self = Self {
+        forward: 10.0,
+        reverse: 5.0,
+        lateral: 5.0,
+        angular: std::f64::consts::PI / 4.0,
+    };
+
+    pub const CRUISER: Self = Self {
+        forward: 5.0,
+        reverse: 2.5,
+        lateral: 2.5,
+        angular: std::f64::consts::PI / 8.0,
+    };
+
+    pub const ASTEROID: Self = Self {
+        forward: 0.0,
+        reverse: 0.0,
+        lateral: 0.0,
+        angular: 0.0,
+    };
+
+    pub const TARGET: Self = Self::ASTEROID;
+
+    pub const MISSILE: Self = Self {
+        forward: 300.0,
+        reverse: 0.0,
+        lateral: 100.0,
+        angular: 8.0 * std::f64::consts::PI,
+    };
+
+    pub const TORPEDO: Self = Self {
+        forward: 70.0,
+        reverse: 0.0,
+        lateral: 20.0,
+        angular: 4.0 * std::f64::consts::PI,
+    };
+
     pub fn magnitude(&self) -> f64 {
         let medial = f64::max(self.forward, self.reverse);
         return f64::sqrt(medial.powf(2.0) + self.lateral.powf(2.0));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_game() {
+        let classes = [
+            Class::Fighter,
+            Class::Frigate,
+            Class::Cruiser,
+            Class::Asteroid,
+            Class::Target,
+            Class::Missile,
+            Class::Torpedo,
+        ];
+
+        for class in classes {
+            let stats = class.default_stats();
+            let acceleration = MaxAcceleration::try_from(class).unwrap();
+
+            assert_eq!(acceleration.forward, stats.max_forward_acceleration);
+            assert_eq!(acceleration.reverse, stats.max_backward_acceleration);
+            assert_eq!(acceleration.lateral, stats.max_lateral_acceleration);
+            assert!((acceleration.angular - stats.max_angular_acceleration).abs() < 1e-9);
+        }
+
+        assert!(MaxAcceleration::try_from(Class::Unknown).is_err());
+    }
+}
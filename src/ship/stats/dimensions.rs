@@ -1,5 +1,7 @@
 use oort_api::prelude::*;
 
+use crate::error::Error;
+
 /// Dimensions of a ship.
 /// Each dimension is given from the centre.
 #[derive(Clone, PartialEq, PartialOrd, Debug)]
@@ -10,32 +12,74 @@ pub struct Dimensions {
     pub right: f64,
 }
 
-impl From<Class> for Dimensions {
-    fn from(value: Class) -> Self {
+impl TryFrom<Class> for Dimensions {
+    type Error = Error;
+
+    fn try_from(value: Class) -> Result<Self, Error> {
         return match value {
-            Class::Fighter => Self {
-                fore: 12.0,
-                aft: 6.0,
-                left: 8.0,
-                right: 8.0,
-            },
-            Class::Frigate => todo!(),
-            Class::Cruiser => todo!(),
-            Class::Asteroid => todo!(),
-            Class::Target => todo!(),
-            Class::Missile => Self {
-                fore: 5.0,
-                aft: 1.0,
-                left: 3.0,
-                right: 3.0,
-            },
-            Class::Torpedo => todo!(),
-            Class::Unknown => todo!(),
+            Class::Fighter => Ok(Self::FIGHTER),
+            Class::Frigate => Ok(Self::FRIGATE),
+            Class::Cruiser => Ok(Self::CRUISER),
+            Class::Asteroid => Ok(Self::ASTEROID),
+            Class::Target => Ok(Self::TARGET),
+            Class::Missile => Ok(Self::MISSILE),
+            Class::Torpedo => Ok(Self::TORPEDO),
+            Class::Unknown => Err(Error::NoStats(value)),
         };
     }
 }
 
 impl Dimensions {
+    pub const FIGHTER: Self = Self {
+        fore: 12.0,
+        aft: 6.0,
+        left: 8.0,
+        right: 8.0,
+    };
+
+    pub const FRIGATE: Self = Self {
+        fore: 60.0,
+        aft: 45.0,
+        left: 20.0,
+        right: 20.0,
+    };
+
+    pub const CRUISER: Self = Self {
+        fore: 120.0,
+        aft: 90.0,
+        left: 40.0,
+        right: 40.0,
+    };
+
+    /// Asteroids come in different sizes. This is the largest.
+    pub const ASTEROID: Self = Self {
+        fore: 50.0,
+        aft: 50.0,
+        left: 50.0,
+        right: 50.0,
+    };
+
+    pub const TARGET: Self = Self {
+        fore: 5.0,
+        aft: 5.0,
+        left: 5.0,
+        right: 5.0,
+    };
+
+    pub const MISSILE: Self = Self {
+        fore: 5.0,
+        aft: 1.0,
+        left: 3.0,
+        right: 3.0,
+    };
+
+    pub const TORPEDO: Self = Self {
+        fore: 8.0,
+        aft: 2.0,
+        left: 3.0,
+        right: 3.0,
+    };
+
     pub fn length(&self) -> f64 {
         return self.fore + self.aft;
     }
@@ -44,6 +88,15 @@ impl Dimensions {
         return self.left + self.right;
     }
 
+    /// Description
+    /// -----------
+    /// Return the average distance from the centre to the edge. Used when the ship's orientation
+    /// isn't known.
+    ///
+    pub fn average_radius(&self) -> f64 {
+        return (self.length() + self.width()) / 4.0;
+    }
+
     pub fn longest(&self) -> f64 {
         return f64::max(
             f64::max(self.fore, self.aft),
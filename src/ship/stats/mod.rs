@@ -1,5 +1,10 @@
 mod acceleration;
 mod dimensions;
+mod radar;
+mod registry;
+mod weapons;
 
 pub use acceleration::MaxAcceleration;
 pub use dimensions::Dimensions;
+pub use registry::ShipStats;
+pub use weapons::{WeaponKind, WeaponStats};
@@ -0,0 +1,8 @@
+/// Capabilities of a ship's radar.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RadarStats {
+    pub power: f64, // W
+
+    /// Effective area of the receiver.
+    pub rx_cross_section: f64, // m²
+}
@@ -0,0 +1,234 @@
+use oort_api::prelude::*;
+
+use crate::error::Error;
+
+use super::{
+    acceleration::MaxAcceleration,
+    dimensions::Dimensions,
+    radar::RadarStats,
+    weapons::{WeaponKind, WeaponStats},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Everything known about a class of ship.
+///
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShipStats {
+    pub acceleration: MaxAcceleration,
+    pub dimensions: Dimensions,
+
+    /// Outline of the hull relative to it's centre, facing along the x axis. Approximated from the
+    /// dimensions rather than taken from the game.
+    pub hull: &'static [(f64, f64)],
+
+    /// Weapons in the order they're fired by index.
+    pub weapons: &'static [WeaponStats],
+    pub radar: Option<RadarStats>,
+    pub abilities: &'static [Ability],
+}
+
+////////////////////////////////////////////////////////////////
+
+const FIGHTER_GUN: WeaponStats = WeaponStats {
+    kind: WeaponKind::Gun,
+    speed: 1000.0,
+    reload: 4.0 * TICK_LENGTH,
+    turret: None,
+};
+
+const MISSILE_LAUNCHER: WeaponStats = WeaponStats {
+    kind: WeaponKind::Launcher(Class::Missile),
+    speed: 100.0,
+    reload: 5.0,
+    turret: None,
+};
+
+const FRIGATE_MAIN_GUN: WeaponStats = WeaponStats {
+    kind: WeaponKind::Gun,
+    speed: 4000.0,
+    reload: 1.0,
+    turret: None,
+};
+
+const FRIGATE_TURRET: WeaponStats = WeaponStats {
+    kind: WeaponKind::Gun,
+    speed: 1000.0,
+    reload: 4.0 * TICK_LENGTH,
+    turret: Some(2.0 * PI),
+};
+
+const CRUISER_FLAK: WeaponStats = WeaponStats {
+    kind: WeaponKind::Gun,
+    speed: 2000.0,
+    reload: 0.4,
+    turret: Some(PI / 2.0),
+};
+
+const CRUISER_MISSILE_LAUNCHER: WeaponStats = WeaponStats {
+    reload: 1.2,
+    ..MISSILE_LAUNCHER
+};
+
+const TORPEDO_LAUNCHER: WeaponStats = WeaponStats {
+    kind: WeaponKind::Launcher(Class::Torpedo),
+    speed: 100.0,
+    reload: 3.0,
+    turret: None,
+};
+
+////////////////////////////////////////////////////////////////
+
+impl ShipStats {
+    pub const FIGHTER: Self = Self {
+        acceleration: MaxAcceleration::FIGHTER,
+        dimensions: Dimensions::FIGHTER,
+        hull: &[(12.0, 0.0), (-6.0, 8.0), (-6.0, -8.0)],
+        weapons: &[FIGHTER_GUN, MISSILE_LAUNCHER],
+        radar: Some(RadarStats {
+            power: 20e3,
+            rx_cross_section: 5.0,
+        }),
+        abilities: &[Ability::Boost],
+    };
+
+    pub const FRIGATE: Self = Self {
+        acceleration: MaxAcceleration::FRIGATE,
+        dimensions: Dimensions::FRIGATE,
+        hull: &[
+            (60.0, 0.0),
+            (30.0, 20.0),
+            (-45.0, 20.0),
+            (-45.0, -20.0),
+            (30.0, -20.0),
+        ],
+        weapons: &[
+            FRIGATE_MAIN_GUN,
+            FRIGATE_TURRET,
+            FRIGATE_TURRET,
+            MISSILE_LAUNCHER,
+        ],
+        radar: Some(RadarStats {
+            power: 100e3,
+            rx_cross_section: 10.0,
+        }),
+        abilities: &[],
+    };
+
+    pub const CRUISER: Self = Self {
+        acceleration: MaxAcceleration::CRUISER,
+        dimensions: Dimensions::CRUISER,
+        hull: &[
+            (120.0, 0.0),
+            (60.0, 40.0),
+            (-90.0, 40.0),
+            (-90.0, -40.0),
+            (60.0, -40.0),
+        ],
+        weapons: &[
+            CRUISER_FLAK,
+            CRUISER_MISSILE_LAUNCHER,
+            CRUISER_MISSILE_LAUNCHER,
+            TORPEDO_LAUNCHER,
+        ],
+        radar: Some(RadarStats {
+            power: 200e3,
+            rx_cross_section: 20.0,
+        }),
+        abilities: &[Ability::Shield],
+    };
+
+    pub const ASTEROID: Self = Self {
+        acceleration: MaxAcceleration::ASTEROID,
+        dimensions: Dimensions::ASTEROID,
+        hull: &[
+            (50.0, 0.0),
+            (35.0, 35.0),
+            (0.0, 50.0),
+            (-35.0, 35.0),
+            (-50.0, 0.0),
+            (-35.0, -35.0),
+            (0.0, -50.0),
+            (35.0, -35.0),
+        ],
+        weapons: &[],
+        radar: None,
+        abilities: &[],
+    };
+
+    pub const TARGET: Self = Self {
+        acceleration: MaxAcceleration::TARGET,
+        dimensions: Dimensions::TARGET,
+        hull: &[(5.0, 5.0), (-5.0, 5.0), (-5.0, -5.0), (5.0, -5.0)],
+        weapons: &[],
+        radar: None,
+        abilities: &[],
+    };
+
+    pub const MISSILE: Self = Self {
+        acceleration: MaxAcceleration::MISSILE,
+        dimensions: Dimensions::MISSILE,
+        hull: &[(5.0, 0.0), (-1.0, 3.0), (-1.0, -3.0)],
+        weapons: &[],
+        radar: Some(RadarStats {
+            power: 20e3,
+            rx_cross_section: 3.0,
+        }),
+        abilities: &[Ability::Boost],
+    };
+
+    pub const TORPEDO: Self = Self {
+        acceleration: MaxAcceleration::TORPEDO,
+        dimensions: Dimensions::TORPEDO,
+        hull: &[(8.0, 0.0), (-2.0, 3.0), (-2.0, -3.0)],
+        weapons: &[],
+        radar: Some(RadarStats {
+            power: 20e3,
+            rx_cross_section: 3.0,
+        }),
+        abilities: &[Ability::Decoy],
+    };
+}
+
+////////////////////////////////////////////////////////////////
+
+impl TryFrom<Class> for ShipStats {
+    type Error = Error;
+
+    fn try_from(value: Class) -> Result<Self, Error> {
+        return match value {
+            Class::Fighter => Ok(Self::FIGHTER),
+            Class::Frigate => Ok(Self::FRIGATE),
+            Class::Cruiser => Ok(Self::CRUISER),
+            Class::Asteroid => Ok(Self::ASTEROID),
+            Class::Target => Ok(Self::TARGET),
+            Class::Missile => Ok(Self::MISSILE),
+            Class::Torpedo => Ok(Self::TORPEDO),
+            Class::Unknown => Err(Error::NoStats(value)),
+        };
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let fighter = ShipStats::try_from(Class::Fighter).unwrap();
+        assert_eq!(fighter.weapons[0].kind, WeaponKind::Gun);
+        assert_eq!(fighter.weapons[0].speed, 1000.0);
+        assert_eq!(
+            fighter.weapons[1].kind,
+            WeaponKind::Launcher(Class::Missile)
+        );
+        assert!(fighter.abilities.contains(&Ability::Boost));
+        assert!(ShipStats::try_from(Class::Unknown).is_err());
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,24 @@
+use oort_api::prelude::*;
+
+/// What a weapon fires.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WeaponKind {
+    Gun,
+
+    /// Launches a ship of the given class, i.e. a missile or torpedo.
+    Launcher(Class),
+}
+
+/// Stats for one of a ship's weapons.
+/// Each weapon's index in it's ship's loadout is the index passed to `fire`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct WeaponStats {
+    pub kind: WeaponKind,
+
+    /// Speed projectiles leave the weapon at, relative to the ship.
+    pub speed: f64, // m/s
+    pub reload: f64, // s
+
+    /// Rate the turret can turn at. Weapons without a turret fire along the ship's heading.
+    pub turret: Option<f64>, // rad/s
+}
@@ -0,0 +1,275 @@
+use oort_api::prelude::*;
+
+use super::{
+    class::ShipClassLoop,
+    control::{Rotation, Thrust, Translation},
+    draw, error,
+    math::{
+        kinematics::{Acceleration, AngularVelocity, Heading, KinematicModel, Position, Velocity},
+        Intercept,
+    },
+    radar::{
+        board::{ContactBoard, UniqueContactBoard},
+        contacts::{Contact, RadarContact},
+        CompositeRadar,
+    },
+    radio::{MissileDatalink, Radio, RadioMessage, Transport},
+    stats::{Dimensions, MaxAcceleration},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Distance from the target at which the terminal run starts.
+const TERMINAL_RANGE: f64 = 1500.0; // m
+
+/// Distance outside the target's hull at which the warhead goes off.
+const FUSE_RANGE: f64 = 50.0; // m
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Torpedo role. Flies towards the target given by the launcher until it's own radar picks up a
+/// frigate or cruiser, then runs in on it. Anything smaller isn't worth the warhead.
+///
+pub struct DefaultTorpedo {
+    radar: CompositeRadar,
+    radio: Transport,
+
+    /// Latest target sent by the launcher.
+    cue: Option<MissileDatalink>,
+
+    /// Contact being tracked as the target.
+    target: Option<usize>,
+
+    acceleration: Vec2,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for DefaultTorpedo {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Velocity for DefaultTorpedo {
+    fn velocity(&self) -> Vec2 {
+        return velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Acceleration for DefaultTorpedo {
+    fn acceleration(&self) -> Vec2 {
+        return self.acceleration;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for DefaultTorpedo {
+    fn heading(&self) -> f64 {
+        return heading();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for DefaultTorpedo {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Translation for DefaultTorpedo {
+    fn set_acceleration(&mut self, acceleration: Vec2) -> Thrust {
+        let thrust = Thrust::allocate(acceleration, self.heading(), &MaxAcceleration::TORPEDO);
+        accelerate(thrust.acceleration);
+        self.acceleration = thrust.acceleration;
+        return thrust;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Rotation for DefaultTorpedo {
+    fn set_angular_acceleration(&mut self, acceleration: f64) {
+        torque(acceleration)
+    }
+
+    fn max_angular_acceleration(&self) -> f64 {
+        return MaxAcceleration::TORPEDO.angular;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl DefaultTorpedo {
+    pub fn new() -> Self {
+        // As with missiles, the launcher's first datalink arrives on the launch channel and says
+        // which channel the rest will arrive on.
+        let mut radio = Transport::new(Radio::new());
+        let cue = if let Some((_, RadioMessage::MissileDatalink(link))) = radio.receive() {
+            radio.radio().set_channel(link.channel);
+
+            Some(link)
+        } else {
+            None
+        };
+
+        return Self {
+            radar: CompositeRadar::new(UniqueContactBoard::new()),
+            radio,
+
+            cue,
+            target: None,
+
+            acceleration: vec2(0.0, 0.0),
+        };
+    }
+
+    /// Description
+    /// -----------
+    /// Return where the launcher expects the target to be now.
+    ///
+    fn cued_position(&self) -> Option<KinematicModel> {
+        return self.cue.as_ref().map(|cue| {
+            let elapsed = current_time() - cue.time;
+            KinematicModel::new(
+                cue.position + (cue.velocity * elapsed),
+                cue.velocity,
+                vec2(0.0, 0.0),
+            )
+        });
+    }
+
+    /// Description
+    /// -----------
+    /// Pick the big ship closest to where we've been cued, or closest to us if we haven't, and
+    /// track it.
+    ///
+    fn update_target(&mut self) {
+        let reference = self
+            .cued_position()
+            .map_or(self.position(), |cue| cue.position());
+
+        let target = self
+            .radar
+            .contacts
+            .iter()
+            .filter(|(_, c)| matches!(c.class(), Class::Frigate | Class::Cruiser))
+            .min_by(|(_, a), (_, b)| {
+                let a = a.position().distance(reference);
+                let b = b.position().distance(reference);
+                a.total_cmp(&b)
+            })
+            .map(|(id, _)| *id);
+
+        if target == self.target {
+            return;
+        }
+
+        if let Some(id) = self.target {
+            self.radar.stop_tracking(id);
+        }
+
+        if let Some(id) = target {
+            if let Err(error) = self.radar.start_tracking(id) {
+                error::report(error);
+            }
+        }
+
+        self.target = target;
+    }
+
+    /// Description
+    /// -----------
+    /// Fly to intercept the target using the full forward acceleration.
+    ///
+    fn intercept(&mut self, target: &KinematicModel) {
+        let max = MaxAcceleration::TORPEDO;
+
+        // Head straight for the target if it can't be intercepted.
+        let solution = match Intercept::new(self, max.forward, target) {
+            Ok(solution) => solution.position(),
+            Err(error) => {
+                error::report(error);
+                target.position()
+            }
+        };
+
+        self.turn_to_face(&solution);
+
+        let direction = self.position_relative_to(&solution).normalize();
+        self.set_acceleration(-direction * max.forward);
+
+        draw::aim_reticle(&solution);
+    }
+
+    /// Description
+    /// -----------
+    /// Run in on a big ship. The hull is large enough that we can aim for the middle of it and
+    /// go off once we're close, dropping a decoy on the way in to draw the point defence.
+    ///
+    fn terminal(&mut self, class: Class, target: &KinematicModel) {
+        activate_ability(Ability::Decoy);
+
+        // Keep the nose on the target itself rather than the intercept point.
+        self.intercept(target);
+        self.turn_to_track(target);
+
+        let size = Dimensions::try_from(class).map_or(0.0, |d| d.fore.max(d.aft));
+        if target.distance_to(self) < size + FUSE_RANGE {
+            explode();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl ShipClassLoop for DefaultTorpedo {
+    fn tick(&mut self) {
+        debug!("Role: Torpedo");
+
+        // Update the target from the launcher.
+        while let Some((_, message)) = self.radio.receive() {
+            if let RadioMessage::MissileDatalink(link) = message {
+                self.cue = Some(link);
+            }
+        }
+
+        self.radar.scan(&self.position());
+        self.update_target();
+
+        let target = self.target.and_then(|id| self.radar.contacts.get(id));
+        let target = match target {
+            Some(Contact::Tracked(contact)) => {
+                Some((contact.class(), KinematicModel::from(contact)))
+            }
+            Some(contact) => Some((
+                contact.class(),
+                KinematicModel::new(contact.position(), contact.velocity(), vec2(0.0, 0.0)),
+            )),
+            None => None,
+        };
+
+        match (target, self.cued_position()) {
+            (Some((class, target)), _) if target.distance_to(self) < TERMINAL_RANGE => {
+                self.terminal(class, &target)
+            }
+            (Some((_, target)), _) | (None, Some(target)) => self.intercept(&target),
+            (None, None) => (),
+        }
+
+        self.radar.adjust(&KinematicModel::from(&*self));
+        draw::heading(self);
+    }
+}
+
+////////////////////////////////////////////////////////////////
@@ -0,0 +1,230 @@
+use oort_api::prelude::*;
+
+use super::{
+    error,
+    math::{
+        geometry::Ellipse,
+        kinematics::{AngularVelocity, Position},
+        FiringSolution,
+    },
+    radar::contacts::{Contact, SearchContact, TrackedContact},
+    stats::Dimensions,
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Decides when to pull the trigger based on the chance a shot fired now hits.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Trigger {
+    /// Lowest probability of a hit worth firing at.
+    threshold: f64,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Trigger {
+    pub fn new(threshold: f64) -> Self {
+        return Self { threshold };
+    }
+
+    /// Description
+    /// -----------
+    /// Return whether a shot fired now is likely enough to hit.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `shooter` - Ship firing along it's heading.
+    /// * `solution` - Where the target is expected to be hit.
+    /// * `area` - Region the target could be in at the time of impact.
+    /// * `dimensions` - Dimensions of the target.
+    ///
+    pub fn pull<T, S>(
+        &self,
+        shooter: &T,
+        solution: &S,
+        area: &Ellipse,
+        dimensions: &Dimensions,
+    ) -> bool
+    where
+        T: AngularVelocity,
+        S: Position,
+    {
+        return hit_probability(shooter, solution, area, dimensions) >= self.threshold;
+    }
+
+    /// Description
+    /// -----------
+    /// Return whether a shot at a radar contact fired now is likely enough to hit.
+    ///
+    pub fn pull_on<T>(
+        &self,
+        shooter: &T,
+        solution: &FiringSolution,
+        contact: &Contact<SearchContact, TrackedContact>,
+    ) -> bool
+    where
+        T: AngularVelocity,
+    {
+        return impact_area(solution, contact)
+            .is_some_and(|(area, dimensions)| self.pull(shooter, solution, &area, &dimensions));
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// Return the region a radar contact could be in by the time a shot fired now reaches it, along
+/// with it's dimensions.
+///
+/// Returns
+/// -------
+/// None if there are no stats for the contact's class.
+///
+pub fn impact_area(
+    solution: &FiringSolution,
+    contact: &Contact<SearchContact, TrackedContact>,
+) -> Option<(Ellipse, Dimensions)> {
+    let dimensions = match Dimensions::try_from(contact.class()) {
+        Ok(dimensions) => dimensions,
+        Err(error) => {
+            error::report(error);
+            return None;
+        }
+    };
+
+    let area = contact.get_area_after(contact.time_elapsed() + solution.time());
+    return Some((area, dimensions));
+}
+
+/// Description
+/// -----------
+/// Return the probability of a shot fired now hitting the target. The target is taken to be
+/// equally likely to be anywhere across the width of it's uncertainty region, and the shot to go
+/// wherever the ship will be pointing when it fires.
+///
+/// Parmaters
+/// ---------
+/// * `shooter` - Ship firing along it's heading.
+/// * `solution` - Where the target is expected to be hit.
+/// * `area` - Region the target could be in at the time of impact.
+/// * `dimensions` - Dimensions of the target.
+///
+pub fn hit_probability<T, S>(
+    shooter: &T,
+    solution: &S,
+    area: &Ellipse,
+    dimensions: &Dimensions,
+) -> f64
+where
+    T: AngularVelocity,
+    S: Position,
+{
+    let bearing = shooter.bearing_to(solution);
+
+    // Distance the shot passes the aim point by, from where we'll be pointing by the time the
+    // gun fires.
+    let aim_error = angle_diff(bearing, shooter.heading_after(TICK_LENGTH));
+    let miss = shooter.distance_to(solution) * aim_error.sin();
+
+    // The target's orientation isn't known so use it's average size.
+    let size = dimensions.average_radius();
+
+    // How far either side of the aim point the target might be.
+    let spread = area.extent(bearing + (PI / 2.0));
+    if spread < f64::EPSILON {
+        return if miss.abs() <= size { 1.0 } else { 0.0 };
+    }
+
+    // Fraction of the spread over which the target would be hit.
+    let low = f64::max(-spread, -size - miss);
+    let high = f64::min(spread, size - miss);
+    return f64::max(high - low, 0.0) / (2.0 * spread);
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::math::kinematics::{Heading, KinematicModel, Velocity};
+
+    struct Shooter {
+        heading: f64,
+        angular_velocity: f64,
+    }
+
+    impl Position for Shooter {
+        fn position(&self) -> Vec2 {
+            return vec2(0.0, 0.0);
+        }
+    }
+
+    impl Velocity for Shooter {
+        fn velocity(&self) -> Vec2 {
+            return vec2(0.0, 0.0);
+        }
+    }
+
+    impl Heading for Shooter {
+        fn heading(&self) -> f64 {
+            return self.heading;
+        }
+    }
+
+    impl AngularVelocity for Shooter {
+        fn angular_velocity(&self) -> f64 {
+            return self.angular_velocity;
+        }
+    }
+
+    #[test]
+    fn test_hit_probability() {
+        let target = KinematicModel::new(vec2(1000.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+        let aim = Shooter {
+            heading: 0.0,
+            angular_velocity: 0.0,
+        };
+        let solution = FiringSolution::new(&aim, 1000.0, &target).unwrap();
+
+        let shooter = Shooter {
+            heading: solution.aim_heading(),
+            angular_velocity: 0.0,
+        };
+        let dimensions = Dimensions::FIGHTER;
+
+        // Pointing straight at a target we know the exact position of.
+        let exact = Ellipse::new(&target.position(), 0.0, 0.0, 0.0);
+        assert_eq!(
+            hit_probability(&shooter, &solution, &exact, &dimensions),
+            1.0
+        );
+
+        // The wider the target might be spread the less likely a hit.
+        let narrow = Ellipse::new(&target.position(), 0.0, 40.0, 10.0);
+        let wide = Ellipse::new(&target.position(), 0.0, 400.0, 10.0);
+        let narrow = hit_probability(&shooter, &solution, &narrow, &dimensions);
+        let wide = hit_probability(&shooter, &solution, &wide, &dimensions);
+        assert!(0.0 < wide && wide < narrow && narrow < 1.0);
+
+        // Turning away from the target.
+        let turning = Shooter {
+            heading: shooter.heading,
+            angular_velocity: 1.0,
+        };
+        assert_eq!(
+            hit_probability(&turning, &solution, &exact, &dimensions),
+            0.0
+        );
+
+        let trigger = Trigger::new(0.5);
+        assert!(trigger.pull(&shooter, &solution, &exact, &dimensions));
+        assert!(!trigger.pull(&turning, &solution, &exact, &dimensions));
+    }
+}
+
+////////////////////////////////////////////////////////////////
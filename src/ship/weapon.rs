@@ -0,0 +1,290 @@
+use oort_api::prelude::*;
+
+use super::{
+    math::kinematics::{AngularVelocity, Heading, Position},
+    radio::ChannelManager,
+    stats::{WeaponKind, WeaponStats},
+};
+
+////////////////////////////////////////////////////////////////
+
+/// Description
+/// -----------
+/// What happened when a weapon was asked to fire.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    Fired,
+
+    /// Still reloading, with the given number of ticks to go.
+    Reloading(u32),
+
+    /// Loaded, or will be next tick, but waiting for the radio to be ready to hand over to the
+    /// missile.
+    Waiting,
+}
+
+/// Description
+/// -----------
+/// One of the ship's weapons.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Weapon {
+    /// Index passed to oort's weapon functions.
+    index: usize,
+    stats: &'static WeaponStats,
+}
+
+/// Description
+/// -----------
+/// A weapon on a turret, along with where the turret is pointing. oort doesn't report a turret's
+/// heading so it's worked out from where the turret has been told to aim and how fast it turns.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Turret {
+    weapon: Weapon,
+
+    /// Heading relative to the ship's. Turrets are carried round as the ship turns.
+    angle: f64,
+
+    /// Angle the turret is turning towards, relative to the ship's heading.
+    target: f64,
+
+    /// Tick the angle was last brought up to date.
+    tick: u32,
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Weapon {
+    /// Description
+    /// -----------
+    /// Return every weapon in a ship's loadout, in index order.
+    ///
+    pub fn loadout(weapons: &'static [WeaponStats]) -> impl Iterator<Item = Self> {
+        return weapons
+            .iter()
+            .enumerate()
+            .map(|(index, stats)| Self { index, stats });
+    }
+
+    pub fn kind(&self) -> WeaponKind {
+        return self.stats.kind;
+    }
+
+    pub fn is_gun(&self) -> bool {
+        return self.stats.kind == WeaponKind::Gun;
+    }
+
+    pub fn is_launcher(&self) -> bool {
+        return matches!(self.stats.kind, WeaponKind::Launcher(_));
+    }
+
+    pub fn has_turret(&self) -> bool {
+        return self.stats.turret.is_some();
+    }
+
+    /// Description
+    /// -----------
+    /// Speed of the projectile relative to the ship.
+    ///
+    pub fn speed(&self) -> f64 {
+        return self.stats.speed;
+    }
+
+    /// Description
+    /// -----------
+    /// Time until the weapon can next fire.
+    ///
+    pub fn reload_time(&self) -> f64 {
+        return reload_ticks(self.index) as f64 * TICK_LENGTH;
+    }
+
+    /// Description
+    /// -----------
+    /// Point the weapon's turret. Weapons without a turret always fire along the ship's heading.
+    ///
+    pub fn aim(&self, heading: f64) {
+        if self.has_turret() {
+            aim(self.index, heading);
+        }
+    }
+
+    /// Description
+    /// -----------
+    /// Fire the weapon if it's loaded.
+    ///
+    pub fn fire(&self) -> Outcome {
+        let reload = reload_ticks(self.index);
+        if reload > 0 {
+            return Outcome::Reloading(reload);
+        }
+
+        fire(self.index);
+        return Outcome::Fired;
+    }
+
+    /// Description
+    /// -----------
+    /// Fire whichever launcher is loaded first, starting a salvo.
+    ///
+    /// Missiles are handed their target on the launch channel as they leave, and the radio takes
+    /// a tick to change channel. So the launch channel is asked for the tick before a launcher is
+    /// loaded and the missile is fired on the next call.
+    ///
+    /// Parmaters
+    /// ---------
+    /// * `launchers` - Launchers to fire from.
+    /// * `channels` - The ship's channels, which the salvo is recorded in.
+    ///
+    pub fn launch(launchers: impl Iterator<Item = Self>, channels: &mut ChannelManager) -> Outcome {
+        let tick = current_tick();
+        let Some((launcher, reload)) = launchers
+            .map(|launcher| (launcher, reload_ticks(launcher.index)))
+            .min_by_key(|(_, reload)| *reload)
+        else {
+            return Outcome::Reloading(u32::MAX);
+        };
+
+        if reload == 0 && channels.launching(tick) {
+            launcher.fire();
+            channels.launch_salvo(tick);
+            return Outcome::Fired;
+        }
+
+        if reload <= 1 {
+            channels.request_launch(tick);
+            return Outcome::Waiting;
+        }
+
+        return Outcome::Reloading(reload);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Position for Turret {
+    fn position(&self) -> Vec2 {
+        return position();
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Heading for Turret {
+    fn heading(&self) -> f64 {
+        return angle_diff(0.0, heading() + self.angle);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl AngularVelocity for Turret {
+    fn angular_velocity(&self) -> f64 {
+        return angular_velocity() + (self.step(1) / TICK_LENGTH);
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+impl Turret {
+    /// Description
+    /// -----------
+    /// Returns
+    /// -------
+    /// None if the weapon isn't on a turret.
+    ///
+    pub fn new(weapon: Weapon) -> Option<Self> {
+        if !weapon.has_turret() {
+            return None;
+        }
+
+        return Some(Self {
+            weapon,
+            angle: 0.0,
+            target: 0.0,
+            tick: current_tick(),
+        });
+    }
+
+    pub fn weapon(&self) -> &Weapon {
+        return &self.weapon;
+    }
+
+    /// Description
+    /// -----------
+    /// Point the turret.
+    ///
+    pub fn aim(&mut self, heading: f64) {
+        self.turn(current_tick());
+        self.target = angle_diff(oort_api::prelude::heading(), heading);
+        self.weapon.aim(heading);
+    }
+
+    pub fn fire(&self) -> Outcome {
+        return self.weapon.fire();
+    }
+
+    /// Description
+    /// -----------
+    /// Return how far the turret turns towards it's target over a number of ticks.
+    ///
+    fn step(&self, ticks: u32) -> f64 {
+        let rate = self.weapon.stats.turret.unwrap_or(0.0);
+        let limit = rate * (ticks as f64) * TICK_LENGTH;
+        return angle_diff(self.angle, self.target).clamp(-limit, limit);
+    }
+
+    /// Description
+    /// -----------
+    /// Bring the turret's angle up to date with the ticks since it was last turned.
+    ///
+    fn turn(&mut self, tick: u32) {
+        self.angle = angle_diff(0.0, self.angle + self.step(tick - self.tick));
+        self.tick = tick;
+    }
+}
+
+////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ship::stats::ShipStats;
+
+    #[test]
+    fn test_loadout() {
+        let weapons: Vec<Weapon> = Weapon::loadout(ShipStats::CRUISER.weapons).collect();
+        assert_eq!(weapons.len(), 4);
+        assert!(weapons
+            .iter()
+            .enumerate()
+            .all(|(index, weapon)| weapon.index == index));
+
+        assert!(weapons[0].is_gun());
+        assert!(weapons[1..].iter().all(Weapon::is_launcher));
+        assert_eq!(weapons[3].kind(), WeaponKind::Launcher(Class::Torpedo));
+        assert_eq!(weapons[0].speed(), 2000.0);
+    }
+
+    #[test]
+    fn test_turret() {
+        let mut weapons = Weapon::loadout(ShipStats::FRIGATE.weapons);
+        assert!(Turret::new(weapons.next().unwrap()).is_none());
+
+        let mut turret = Turret::new(weapons.next().unwrap()).unwrap();
+        turret.target = PI / 2.0;
+
+        // Turns at it's limited rate, then holds once it's on target.
+        let rate = turret.weapon.stats.turret.unwrap();
+        turret.turn(turret.tick + 1);
+        assert!((turret.angle - (rate * TICK_LENGTH)).abs() < 1e-9);
+
+        turret.turn(turret.tick + 60);
+        assert!((turret.angle - (PI / 2.0)).abs() < 1e-9);
+        assert_eq!(turret.step(1), 0.0);
+    }
+}
+
+////////////////////////////////////////////////////////////////